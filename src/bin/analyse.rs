@@ -0,0 +1,62 @@
+use minesweeper::{DynResult, FieldOptions, MineField};
+
+// rates boards generated from a preset, one line per board:
+//
+//     analyse <preset> [count] [explain]
+//
+// with explain every board is followed by the steps solving it
+fn main() -> DynResult<()> {
+    let mut args = std::env::args().skip(1);
+
+    let preset = args
+        .next()
+        .ok_or("usage: analyse <preset> [count] [explain]")?;
+    let count = match args.next() {
+        Some(count) => count.parse()?,
+        None => 10,
+    };
+    let explain = args.next().is_some_and(|arg| arg == "explain");
+
+    let options = FieldOptions::load_preset(preset)?;
+    let mut placement = options.placement.build();
+
+    println!("score guesses level frontier 3bv openings");
+
+    for _ in 0..count {
+        let mut minefield = MineField::new(options);
+        let (x, y) = get_center(&minefield);
+
+        minefield.place_mines(x, y, placement.as_mut());
+
+        let rating = minefield.rate(x, y);
+
+        println!(
+            "{} {} {} {} {} {}",
+            rating.score,
+            rating.guesses,
+            rating.level,
+            rating.frontier,
+            rating.bbbv,
+            rating.openings
+        );
+
+        if explain {
+            for line in minefield.explain_solution(x, y) {
+                println!("    {line}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// the usable cell closest to the middle of the first layer
+fn get_center(minefield: &MineField) -> (usize, usize) {
+    let options = minefield.options();
+    let (cx, cy) = (options.cols / 2, options.rows / 2);
+
+    minefield
+        .get_coords_all()
+        .min_by_key(|&(x, y)| x.abs_diff(cx) + y.abs_diff(cy))
+        .unwrap_or((0, 0))
+}
@@ -0,0 +1,41 @@
+use minesweeper::{Difficulty, FieldOptions, MineField};
+
+// prints a pack of puzzles where the givens left prove every mine:
+//
+//     generate <cols> <rows> <mines> [count] [easy|advanced]
+fn main() -> minesweeper::DynResult<()> {
+    let usage = "usage: generate <cols> <rows> <mines> [count] [easy|advanced]";
+    let mut args = std::env::args().skip(1);
+
+    let mut number = || -> minesweeper::DynResult<usize> { Ok(args.next().ok_or(usage)?.parse()?) };
+    let (cols, rows, mines) = (number()?, number()?, number()?);
+
+    let count = match args.next() {
+        Some(count) => count.parse()?,
+        None => 10,
+    };
+
+    let difficulty = match args.next().as_deref() {
+        None => Difficulty::Any,
+        Some("easy") => Difficulty::EasyLogic,
+        Some("advanced") => Difficulty::Advanced,
+        Some(_) => return Err(usage.into()),
+    };
+
+    let options = FieldOptions {
+        cols,
+        rows,
+        mines,
+        difficulty,
+        ..Default::default()
+    };
+
+    for index in 0..count {
+        let puzzle = MineField::generate_puzzle(options)?;
+
+        println!("name generated {}", index + 1);
+        println!("{}", puzzle.to_puzzle());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use minesweeper::{BoardFile, DynResult, FieldOptions, MineField, SharedBoard};
+
+const USAGE: &str =
+    "usage: share seed <preset> <seed> <x> <y> | share board <file> <x> <y> | share decode <code>";
+
+// makes share codes from a seed or a board file, or shows the board of a code:
+//
+//     share seed <preset> <seed> <x> <y>
+//     share board <file> <x> <y>
+//     share decode <code>
+fn main() -> DynResult<()> {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
+
+    match args[..] {
+        ["seed", preset, seed, x, y] => {
+            let options = FieldOptions::load_preset(preset)?;
+            let board = SharedBoard::from_seed(options, seed.parse()?, (x.parse()?, y.parse()?));
+
+            println!("{}", board.encode()?);
+        }
+        ["board", file, x, y] => {
+            let board = BoardFile::load(file)?;
+            let field = MineField::from_board_file(&board, FieldOptions::default())?;
+            let board = SharedBoard::from_field(&field, (x.parse()?, y.parse()?));
+
+            println!("{}", board.encode()?);
+        }
+        ["decode", code] => {
+            let board = SharedBoard::decode(code)?;
+            let options = board.options;
+            let (x, y) = board.first_click;
+
+            println!(
+                "{}x{}, {} layers, {} mines, first opened at ({x},{y})",
+                options.cols,
+                options.rows,
+                options.layers,
+                board.mines.len()
+            );
+
+            if let Some(seed) = board.seed {
+                println!("seed {seed}");
+            }
+
+            let mut field = MineField::new(options);
+            field.place_mines(x, y, &mut board.placement());
+
+            print!("{}", field.to_layout());
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    Ok(())
+}
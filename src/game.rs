@@ -1,16 +1,29 @@
+pub mod clock;
 pub mod components;
+pub mod editor;
 pub mod rendering;
+pub mod settings;
+pub mod trainer;
 
+use clock::Clock;
 use components::{
     minefield::{CellState, MineCell},
+    puzzles::PuzzleSession,
     GameComponents,
 };
 use rendering::Renderer;
+use settings::Settings;
 
 use super::{Context, DynResult, SdlResult};
-use std::time::{Duration, Instant};
-
-pub use components::minefield::FieldOptions;
+use std::{mem, rc::Rc, time::Duration};
+
+pub use components::minefield::{
+    BigCount, BoardFile, BoardShape, ClusteredPlacement, Difficulty, FieldOptions, FirstClick,
+    GameAnalysis, Hint, LayoutPlacement, Luck, MineField, MinePlacement, Move, MoveReport,
+    Neighbourhood, NoGuessPlacement, OffsetMask, Pattern, Placement, Rating, SeededPlacement,
+    SharedBoard, SymmetricPlacement, Symmetry, Tiling, TimedMove, Topology, UniformPlacement,
+    Verdict,
+};
 
 #[derive(PartialEq)]
 pub enum GameState {
@@ -19,32 +32,75 @@ pub enum GameState {
     Quitted,
 }
 
+#[derive(Clone, Copy)]
+pub struct GameResult {
+    pub won: bool,
+    pub millis: u64,
+    pub rating: Rating,
+    // the mine chances were shown during the game
+    pub assisted: bool,
+}
+
 pub struct GameHandler {
     state: GameState,
     components: GameComponents,
     renderer: Renderer,
+    results: Vec<GameResult>,
+    first_click: (usize, usize),
+    // the replay of the current game
+    moves: Vec<TimedMove>,
+    // shown over the board of a finished game
+    analysis: Option<GameAnalysis>,
+    heatmap_shown: bool,
+    assisted: bool,
+    // the pack played instead of generated boards
+    puzzles: Option<PuzzleSession>,
+    save_error: String,
 }
 
 // public methods
 impl GameHandler {
-    pub fn init(context: &Context, options: FieldOptions) -> DynResult<Self> {
+    pub fn init(
+        context: &Context,
+        options: FieldOptions,
+        settings: Settings,
+        clock: Rc<dyn Clock>,
+        placement: Box<dyn MinePlacement>,
+        puzzles: Option<PuzzleSession>,
+    ) -> DynResult<Self> {
         let state = GameState::Playing(true);
-        let components = GameComponents::new(options);
+        let components = GameComponents::new(options, clock, placement);
 
         let clamped_options = components.minefield.options();
-        let renderer = Renderer::init(context, clamped_options)?;
+        let renderer = Renderer::init(context, clamped_options, settings)?;
 
-        Ok(Self {
+        let mut handler = Self {
             state,
             components,
             renderer,
-        })
+            results: Vec::new(),
+            first_click: (0, 0),
+            moves: Vec::new(),
+            analysis: None,
+            heatmap_shown: false,
+            assisted: false,
+            puzzles,
+            save_error: String::new(),
+        };
+
+        handler.load_puzzle();
+
+        Ok(handler)
     }
 
     pub fn is_active(&self) -> bool {
         self.state != GameState::Quitted
     }
 
+    pub fn results(&self) -> &[GameResult] {
+        &self.results
+    }
+
     pub fn left_click(&mut self, x: i32, y: i32) {
         if self.renderer.button_contains(x, y) {
             self.components.button.click();
@@ -54,6 +110,7 @@ impl GameHandler {
 
         if matches!(self.state, GameState::Playing(_)) {
             self.renderer.draw_button_openeyed();
+            self.renderer.clear_hovered_cells();
             self.update_target_cell(x, y);
         }
     }
@@ -106,24 +163,178 @@ impl GameHandler {
         }
     }
 
+    // shows the mine chance of the hidden cell under the cursor; on stacked
+    // layers its hidden neighbours light up on every layer in view
+    pub fn hover(&mut self, x: i32, y: i32) {
+        let cell = self.get_cell(x, y);
+        self.renderer.hover_cell(cell.as_ref());
+
+        let stacked = self.components.minefield.options().layers > 1;
+
+        if !stacked
+            || !matches!(self.state, GameState::Playing(_))
+            || !self.renderer.is_target_changed(x, y)
+        {
+            return;
+        }
+
+        self.renderer.clear_hovered_cells();
+
+        match cell {
+            Some(cell) => {
+                self.renderer.set_target_cell(&cell);
+
+                if cell.is_hidden() {
+                    self.draw_hidden_around(cell.x(), cell.y());
+                }
+            }
+            None => self.renderer.reset_target_cell(),
+        }
+    }
+
     pub fn right_click(&mut self, x: i32, y: i32) {
         self.toggle_flag(x, y);
     }
 
+    // only does something when a single layer is shown
+    pub fn scroll_layer(&mut self, delta: i32) {
+        if !self.renderer.scroll_layer(delta) {
+            return;
+        }
+
+        let finished = matches!(self.state, GameState::Finished(_));
+
+        for (x, y) in self.components.minefield.get_coords_all() {
+            let cell = unsafe { self.components.minefield.get_cell_unchecked(x, y) };
+            self.renderer.draw_cell_default(&cell);
+
+            if finished {
+                self.renderer.draw_cell_final(&cell);
+            }
+        }
+
+        if let Some(analysis) = &self.analysis {
+            self.renderer.draw_analysis(analysis);
+        }
+    }
+
+    // explains the next cell that can be proved in the title and marks it
+    pub fn hint(&mut self) {
+        if self.state != GameState::Playing(false) {
+            return;
+        }
+
+        let minefield = &self.components.minefield;
+
+        let Some(hint) = minefield.get_hint() else {
+            self.show_note("nothing can be proved, a guess is needed");
+            return;
+        };
+
+        let cell = unsafe { minefield.get_cell_unchecked(hint.x, hint.y) };
+        self.renderer.draw_hint(&cell, hint.is_safe);
+        self.show_note(hint.text);
+    }
+
+    // shows the share code of the board in the title and copies it,
+    // once the mines are placed
+    pub fn share(&mut self) {
+        if self.state == GameState::Playing(true) {
+            self.show_note("nothing to share before the first click");
+            return;
+        }
+
+        let board = SharedBoard::from_field(&self.components.minefield, self.first_click);
+
+        let note = match board.encode() {
+            Ok(code) => match self.renderer.copy_to_clipboard(&code) {
+                Ok(()) => format!("share code {code} copied"),
+                Err(e) => format!("share code {code}, can't copy it: {e}"),
+            },
+            Err(e) => format!("can't share the board: {e}"),
+        };
+
+        self.show_note(note);
+    }
+
+    // extra text in the window title, kept until the next note
+    pub fn show_note(&mut self, note: impl Into<String>) {
+        self.renderer.set_title_note(Some(note.into()));
+    }
+
+    // marks every move of a finished game on the board and prints the report,
+    // the second call brings the board back
+    pub fn toggle_analysis(&mut self) {
+        if !matches!(self.state, GameState::Finished(_)) {
+            return;
+        }
+
+        let minefield = &self.components.minefield;
+
+        if self.analysis.take().is_some() {
+            for (x, y) in minefield.get_coords_all() {
+                let cell = unsafe { minefield.get_cell_unchecked(x, y) };
+                self.renderer.draw_cell_default(&cell);
+                self.renderer.draw_cell_final(&cell);
+            }
+
+            return;
+        }
+
+        let analysis = minefield.analyse_game(&self.moves);
+
+        for line in minefield.describe_analysis(&analysis) {
+            println!("{line}");
+        }
+
+        self.renderer.draw_analysis(&analysis);
+        self.analysis = Some(analysis);
+    }
+
+    // the game becomes assisted once the chances have been shown in it,
+    // outside of a game only the choice for the next one is kept
+    pub fn toggle_heatmap(&mut self) {
+        self.heatmap_shown = !self.heatmap_shown;
+
+        if self.state == GameState::Playing(false) {
+            self.update_heatmap();
+        }
+    }
+
+    // the next puzzle of the pack is brought up, the current one counts as not played
+    pub fn skip_puzzle(&mut self) {
+        let Some(puzzles) = &mut self.puzzles else {
+            return;
+        };
+
+        puzzles.skip();
+        self.reset_game();
+    }
+
     pub fn quit(&mut self) {
         self.state = GameState::Quitted;
     }
 
-    pub fn next_frame(&mut self, now: Instant) -> SdlResult {
-        if let Some(secs) = self.components.secs_counter.get_secs() {
-            self.renderer.draw_secs_counter(secs);
+    // time the main loop may sleep before the display needs an update
+    pub fn next_update_in(&self) -> Option<Duration> {
+        let step = self.renderer.timer_step();
+        let millis = self.components.secs_counter.millis_to_next(step)?;
+
+        Some(Duration::from_millis(millis))
+    }
+
+    pub fn update_display(&mut self) -> SdlResult {
+        if !self.save_error.is_empty() {
+            return Err(mem::take(&mut self.save_error));
         }
 
-        self.renderer.render_frame()?;
+        let step = self.renderer.timer_step();
 
-        spin_sleep::sleep(Duration::from_nanos(1_000_000_000 / 60).saturating_sub(now.elapsed()));
+        if let Some(millis) = self.components.secs_counter.get_millis(step) {
+            self.renderer.draw_secs_counter(millis);
+        }
 
-        Ok(())
+        self.renderer.render_frame()
     }
 }
 
@@ -142,20 +353,28 @@ impl GameHandler {
         };
 
         match cell.state() {
-            CellState::Hidden => self.renderer.draw_cell_hovered(&cell),
-            CellState::Flagged => (),
+            CellState::Hidden => {
+                self.renderer.draw_cell_hovered(&cell);
+
+                if self.components.minefield.options().layers > 1 {
+                    self.draw_hidden_around(cell.x(), cell.y());
+                }
+            }
+            CellState::Flagged(_) => (),
             CellState::Revealed if cell.kind().to_int() == 0 => (),
-            CellState::Revealed => {
-                let minefield = &self.components.minefield;
-                let (x, y) = (cell.x(), cell.y());
+            CellState::Revealed => self.draw_hidden_around(cell.x(), cell.y()),
+        }
+    }
 
-                for (x, y) in minefield.get_coords_around(x, y) {
-                    let cell = unsafe { minefield.get_cell_unchecked(x, y) };
+    // cells on layers out of view are skipped by the renderer
+    fn draw_hidden_around(&mut self, x: usize, y: usize) {
+        let minefield = &self.components.minefield;
 
-                    if cell.is_hidden() {
-                        self.renderer.draw_cell_hovered(&cell);
-                    }
-                }
+        for (x, y) in minefield.get_coords_around(x, y) {
+            let cell = unsafe { minefield.get_cell_unchecked(x, y) };
+
+            if cell.is_hidden() {
+                self.renderer.draw_cell_hovered(&cell);
             }
         }
     }
@@ -175,7 +394,38 @@ impl GameHandler {
             }
 
             let (init_x, init_y) = (cell.x(), cell.y());
-            unsafe { self.components.minefield.place_random_mines(init_x, init_y) };
+            self.first_click = (init_x, init_y);
+
+            // puzzles come with their mines
+            if self.puzzles.is_some() {
+                self.state = GameState::Playing(false);
+                self.components.secs_counter.start();
+
+                self.handle_left_click(x, y);
+                return;
+            }
+
+            let components = &mut self.components;
+            let minefield = &mut components.minefield;
+
+            let mines = minefield.options().mines as i32;
+            let placed = minefield.place_mines_timed(
+                init_x,
+                init_y,
+                components.placement.as_mut(),
+                components.clock.as_ref(),
+            );
+
+            // cells may hold several mines, so the safe ones are counted afterwards
+            let safe_cells = minefield.count_hidden_safe_cells();
+            components.cells_counter.set_count(safe_cells as i32);
+
+            // the strategy may place another number of mines than asked
+            if placed as i32 != mines {
+                components.flags_counter.add(placed as i32 - mines);
+                self.renderer
+                    .draw_flags_counter(components.flags_counter.get_count());
+            }
 
             self.state = GameState::Playing(false);
             self.components.secs_counter.start();
@@ -185,14 +435,18 @@ impl GameHandler {
         }
 
         match cell.state() {
-            CellState::Hidden => self.open_cell(cell),
-            CellState::Flagged => (),
+            CellState::Hidden => {
+                self.record_move(Move::Open(cell.x(), cell.y()));
+                self.open_cell_lazily(cell);
+            }
+            CellState::Flagged(_) => (),
             CellState::Revealed if cell.kind().to_int() == 0 => (),
             CellState::Revealed => {
                 let (x, y) = (cell.x(), cell.y());
                 let flags_around = self.components.minefield.count_flags_around(x, y);
 
                 if cell.kind().to_int() == flags_around {
+                    self.record_move(Move::Chord(x, y));
                     self.open_around(x, y);
                 }
             }
@@ -202,11 +456,30 @@ impl GameHandler {
             self.state = GameState::Finished(true);
         }
 
+        self.update_heatmap();
+
         if matches!(self.state, GameState::Finished(_)) {
             self.finish();
         }
     }
 
+    // the mines of a lazy field may move right before the cell is opened
+    fn open_cell_lazily(&mut self, cell: MineCell) {
+        let (x, y) = (cell.x(), cell.y());
+        let minefield = &mut self.components.minefield;
+
+        if !minefield.apply_luck(x, y, self.first_click) {
+            self.open_cell(cell);
+            return;
+        }
+
+        let safe_cells = minefield.count_hidden_safe_cells();
+        self.components.cells_counter.set_count(safe_cells as i32);
+
+        let cell = unsafe { minefield.get_cell_unchecked(x, y) };
+        self.open_cell(cell);
+    }
+
     fn open_cell(&mut self, mut cell: MineCell) {
         if cell.is_mined() {
             self.state = GameState::Finished(false);
@@ -229,7 +502,7 @@ impl GameHandler {
             let cell = unsafe { self.components.minefield.get_cell_unchecked(x, y) };
 
             if cell.is_hidden() {
-                self.open_cell(cell);
+                self.open_cell_lazily(cell);
             }
         }
     }
@@ -240,15 +513,21 @@ impl GameHandler {
             None => return,
         };
 
+        let mines_per_cell = self.components.minefield.options().mines_per_cell;
         let flags_counter = &mut self.components.flags_counter;
 
+        // cycles through one flag per mine the cell can hold
         match cell.state() {
             CellState::Hidden => {
                 flags_counter.decrement();
-                cell.set_state(CellState::Flagged);
+                cell.set_state(CellState::Flagged(1));
             }
-            CellState::Flagged => {
-                flags_counter.increment();
+            CellState::Flagged(flags) if flags < mines_per_cell => {
+                flags_counter.decrement();
+                cell.set_state(CellState::Flagged(flags + 1));
+            }
+            CellState::Flagged(flags) => {
+                flags_counter.add(flags as i32);
                 cell.set_state(CellState::Hidden);
             }
             CellState::Revealed => return,
@@ -256,10 +535,39 @@ impl GameHandler {
 
         self.renderer.draw_flags_counter(flags_counter.get_count());
 
+        let flags = match cell.state() {
+            CellState::Flagged(flags) => flags,
+            _ => 0,
+        };
+        self.record_move(Move::Flag(cell.x(), cell.y(), flags));
+
         self.components.minefield.set_cell(&cell);
         self.renderer.draw_cell_default(&cell);
     }
 
+    // the chances only make sense once the mines are placed and until the game ends
+    fn update_heatmap(&mut self) {
+        let minefield = &self.components.minefield;
+
+        let heatmap = (self.heatmap_shown && self.state == GameState::Playing(false))
+            .then(|| minefield.get_probabilities())
+            .flatten();
+
+        if heatmap.is_some() {
+            self.assisted = true;
+        }
+
+        self.renderer.set_heatmap(heatmap);
+
+        for (x, y) in minefield.get_coords_all() {
+            let cell = unsafe { minefield.get_cell_unchecked(x, y) };
+
+            if cell.is_hidden() {
+                self.renderer.draw_cell_default(&cell);
+            }
+        }
+    }
+
     fn get_cell(&self, x: i32, y: i32) -> Option<MineCell> {
         if let Some((x, y)) = self.renderer.get_cell_pos(x, y) {
             Some(unsafe { self.components.minefield.get_cell_unchecked(x, y) })
@@ -274,12 +582,25 @@ impl GameHandler {
             return;
         }
 
+        self.reset_game();
+    }
+
+    fn reset_game(&mut self) {
         self.state = GameState::Playing(true);
         self.components.reset();
+        self.moves.clear();
+        self.analysis = None;
+        self.assisted = false;
+        self.renderer.set_heatmap(None);
 
         let flags_count = self.components.flags_counter.get_count();
 
+        self.renderer.set_title_result(None);
+        self.renderer.clear_hovered_cells();
+        self.renderer.reset_target_cell();
         self.renderer.draw_initial_state(flags_count);
+
+        self.load_puzzle();
     }
 
     fn restart_if_idle(&mut self) {
@@ -297,12 +618,88 @@ impl GameHandler {
 
         self.components.flags_counter.set_count(flags_count);
         self.components.minefield.reset();
+        self.moves.clear();
 
         self.renderer.draw_initial_state(flags_count);
+
+        self.load_puzzle();
+    }
+
+    // puts the current puzzle of the pack on the board, if there is one
+    fn load_puzzle(&mut self) {
+        let Some(puzzles) = &self.puzzles else {
+            return;
+        };
+
+        let components = &mut self.components;
+        components.minefield = puzzles.field();
+
+        let minefield = &components.minefield;
+        let mines = minefield.options().mines as i32;
+
+        components.flags_counter.set_count(mines);
+        components
+            .cells_counter
+            .set_count(minefield.count_hidden_safe_cells() as i32);
+
+        self.renderer.draw_initial_state(mines);
+        self.renderer.set_title_note(Some(puzzles.describe()));
+
+        for (x, y) in minefield.get_coords_all() {
+            let cell = unsafe { minefield.get_cell_unchecked(x, y) };
+
+            if cell.state() == CellState::Revealed {
+                self.renderer.draw_cell_default(&cell);
+            }
+        }
+    }
+
+    fn record_move(&mut self, step: Move) {
+        let millis = self.components.secs_counter.elapsed_millis();
+        self.moves.push(TimedMove { step, millis });
     }
 
     fn finish(&mut self) {
-        self.components.secs_counter.stop();
+        let secs_counter = &mut self.components.secs_counter;
+        secs_counter.stop();
+
+        let won = self.state == GameState::Finished(true);
+        let millis = secs_counter.elapsed_millis();
+
+        let (init_x, init_y) = self.first_click;
+        let rating = self.components.minefield.rate(init_x, init_y);
+
+        let result = GameResult {
+            won,
+            millis,
+            rating,
+            assisted: self.assisted,
+        };
+
+        self.results.push(result);
+
+        if let Some(puzzles) = &mut self.puzzles {
+            let name = puzzles.describe();
+
+            match puzzles.record(won, millis) {
+                Ok(stats) => println!(
+                    "{name}: {} of {} attempts solved, best {}",
+                    stats.solves,
+                    stats.attempts,
+                    stats.best_millis.map_or(String::from("-"), |best| format!(
+                        "{}.{:03} s",
+                        best / 1000,
+                        best % 1000
+                    ))
+                ),
+                Err(error) => self.save_error = error.to_string(),
+            }
+        }
+
+        if won {
+            self.renderer.draw_win_time(millis);
+            self.renderer.set_title_result(Some(result));
+        }
 
         self.renderer.draw_button_by_gamestate(&self.state);
 
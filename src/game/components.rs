@@ -1,10 +1,14 @@
 pub mod button;
 pub mod counters;
 pub mod minefield;
+pub mod puzzles;
 
 use button::Button;
 use counters::{Counter, SecsCounter};
-use minefield::{FieldOptions, MineField};
+use minefield::{FieldOptions, MineField, MinePlacement};
+
+use crate::game::clock::Clock;
+use std::rc::Rc;
 
 pub struct GameComponents {
     pub button: Button,
@@ -12,38 +16,46 @@ pub struct GameComponents {
     pub flags_counter: Counter,
     pub secs_counter: SecsCounter,
     pub minefield: MineField,
+    pub placement: Box<dyn MinePlacement>,
+    pub clock: Rc<dyn Clock>,
 }
 
 impl GameComponents {
-    pub fn new(options: FieldOptions) -> Self {
+    pub fn new(
+        options: FieldOptions,
+        clock: Rc<dyn Clock>,
+        placement: Box<dyn MinePlacement>,
+    ) -> Self {
         let minefield = MineField::new(options);
 
         let clamped_options = minefield.options();
-        let FieldOptions { cols, rows, mines } = clamped_options;
+        let FieldOptions { mines, .. } = clamped_options;
 
-        let cells_count = (cols * rows - mines) as i32;
+        let cells_count = (clamped_options.cells_count() - mines) as i32;
         let flags_count = mines as i32;
 
         Self {
             button: Button::default(),
             cells_counter: Counter::new(cells_count),
             flags_counter: Counter::new(flags_count),
-            secs_counter: SecsCounter::default(),
+            secs_counter: SecsCounter::new(clock.clone()),
             minefield,
+            placement,
+            clock,
         }
     }
 
     pub fn reset(&mut self) {
         let options = self.minefield.options();
-        let FieldOptions { cols, rows, mines } = options;
+        let FieldOptions { mines, .. } = options;
 
-        let cells_count = (cols * rows - mines) as i32;
+        let cells_count = (options.cells_count() - mines) as i32;
         let flags_count = mines as i32;
 
         self.button.release();
         self.cells_counter.set_count(cells_count);
         self.flags_counter.set_count(flags_count);
-        self.secs_counter.stop();
+        self.secs_counter.reset();
         self.minefield.reset();
     }
 }
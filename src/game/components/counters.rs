@@ -1,12 +1,15 @@
-use std::time::Instant;
+use crate::game::clock::Clock;
+use std::{rc::Rc, time::Duration};
 
 pub struct Counter {
     count: i32,
 }
 
 pub struct SecsCounter {
-    now: Instant,
-    secs: u64,
+    clock: Rc<dyn Clock>,
+    now: Duration,
+    millis: u64,
+    ticks: Option<u64>,
     is_idle: bool,
 }
 
@@ -23,8 +26,8 @@ impl Counter {
         self.count = count;
     }
 
-    pub fn increment(&mut self) {
-        self.count += 1;
+    pub fn add(&mut self, count: i32) {
+        self.count += count;
     }
 
     pub fn decrement(&mut self) {
@@ -32,39 +35,130 @@ impl Counter {
     }
 }
 
-impl Default for SecsCounter {
-    fn default() -> Self {
+impl SecsCounter {
+    pub fn new(clock: Rc<dyn Clock>) -> Self {
         Self {
-            now: Instant::now(),
-            secs: 0,
+            now: clock.now(),
+            clock,
+            millis: 0,
+            ticks: None,
             is_idle: true,
         }
     }
-}
 
-impl SecsCounter {
-    pub fn get_secs(&mut self) -> Option<u64> {
+    // returns elapsed milliseconds each time a new `step` is reached
+    pub fn get_millis(&mut self, step: u64) -> Option<u64> {
         if self.is_idle {
             return None;
         }
 
-        let elapsed = self.now.elapsed().as_secs() + 1;
+        let elapsed = self.elapsed_millis();
+        let ticks = Some(elapsed / step);
 
-        if self.secs < elapsed {
-            self.secs = elapsed;
+        if self.ticks < ticks {
+            self.ticks = ticks;
             Some(elapsed)
         } else {
             None
         }
     }
 
+    // milliseconds left until the next `step` is reached
+    pub fn millis_to_next(&self, step: u64) -> Option<u64> {
+        if self.is_idle {
+            None
+        } else {
+            Some(step - self.elapsed_millis() % step)
+        }
+    }
+
+    pub fn elapsed_millis(&self) -> u64 {
+        if self.is_idle {
+            self.millis
+        } else {
+            self.clock.now().saturating_sub(self.now).as_millis() as u64
+        }
+    }
+
     pub fn start(&mut self) {
-        self.now = Instant::now();
-        self.secs = 0;
+        self.now = self.clock.now();
+        self.millis = 0;
+        self.ticks = None;
         self.is_idle = false;
     }
 
     pub fn stop(&mut self) {
+        if !self.is_idle {
+            self.millis = self.elapsed_millis();
+            self.is_idle = true;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.millis = 0;
+        self.ticks = None;
         self.is_idle = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::clock::ManualClock;
+
+    fn counter() -> (Rc<ManualClock>, SecsCounter) {
+        let clock = Rc::new(ManualClock::default());
+        let counter = SecsCounter::new(clock.clone());
+
+        (clock, counter)
+    }
+
+    #[test]
+    fn idle_until_started() {
+        let (clock, mut counter) = counter();
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(counter.elapsed_millis(), 0);
+        assert_eq!(counter.get_millis(1000), None);
+        assert_eq!(counter.millis_to_next(1000), None);
+    }
+
+    #[test]
+    fn counts_from_the_start() {
+        let (clock, mut counter) = counter();
+        clock.advance(Duration::from_secs(3));
+        counter.start();
+        clock.advance(Duration::from_millis(1500));
+
+        assert_eq!(counter.elapsed_millis(), 1500);
+        assert_eq!(counter.get_millis(1000), Some(1500));
+        assert_eq!(counter.get_millis(1000), None);
+        assert_eq!(counter.millis_to_next(1000), Some(500));
+    }
+
+    #[test]
+    fn keeps_the_win_time_once_stopped() {
+        let (clock, mut counter) = counter();
+        counter.start();
+        clock.advance(Duration::from_millis(12_345));
+        counter.stop();
+        clock.advance(Duration::from_secs(60));
+        counter.stop();
+
+        assert_eq!(counter.elapsed_millis(), 12_345);
+        assert_eq!(counter.get_millis(1000), None);
+
+        counter.reset();
+        assert_eq!(counter.elapsed_millis(), 0);
+    }
+
+    #[test]
+    fn clock_set_back_reads_zero() {
+        let (clock, mut counter) = counter();
+        clock.set(Duration::from_secs(10));
+        counter.start();
+        clock.set(Duration::from_secs(4));
+
+        assert_eq!(counter.elapsed_millis(), 0);
+    }
+}
@@ -1,8 +1,41 @@
+mod analysis;
+mod big_count;
+mod board_file;
+mod drill;
+mod editing;
+mod explain;
+mod layouts;
 mod minecell;
-
+mod neighbourhood;
+mod placement;
+mod preset;
+mod probability;
+mod puzzle;
+mod rating;
+mod shape;
+mod share_code;
+mod solver;
+
+use crate::game::clock::{Clock, SystemClock};
 use minecell::MineCell as CellInternal;
-
+use solver::{Knowledge, Solver};
+use std::time::Duration;
+
+pub use analysis::{GameAnalysis, Move, MoveReport, TimedMove, Verdict};
+pub use big_count::BigCount;
+pub use board_file::BoardFile;
+pub use drill::{Drill, Pattern};
+pub use explain::Hint;
 pub use minecell::{CellKind, CellState};
+pub use neighbourhood::{Neighbourhood, OffsetMask};
+pub use placement::{
+    ClusteredPlacement, LayoutPlacement, MinePlacement, NoGuessPlacement, Placement,
+    SeededPlacement, SymmetricPlacement, Symmetry, UniformPlacement,
+};
+pub use puzzle::Puzzle;
+pub use rating::Rating;
+pub use shape::BoardShape;
+pub use share_code::SharedBoard;
 
 pub struct MineCell {
     cell: CellInternal,
@@ -10,6 +43,8 @@ pub struct MineCell {
     y: usize,
 }
 
+// layers are stored side by side: column x belongs to layer x / cols
+#[derive(Clone)]
 pub struct MineField {
     options: FieldOptions,
     cells: Vec<Vec<CellInternal>>,
@@ -33,7 +68,7 @@ impl MineCell {
     }
 
     pub fn is_mined(&self) -> bool {
-        self.cell.kind == CellKind::Mined
+        matches!(self.cell.kind, CellKind::Mined(_))
     }
 
     pub fn is_hidden(&self) -> bool {
@@ -41,7 +76,7 @@ impl MineCell {
     }
 
     pub fn is_flagged(&self) -> bool {
-        self.cell.state == CellState::Flagged
+        matches!(self.cell.state, CellState::Flagged(_))
     }
 
     // (unused)
@@ -56,44 +91,217 @@ impl MineCell {
 
 impl MineField {
     pub fn new(options: FieldOptions) -> Self {
-        let cols = options.cols.clamp(9, 30);
-        let rows = options.rows.clamp(9, 30);
-        let mines = options.mines.clamp(10, cols * rows - 1);
-
-        let options = FieldOptions { cols, rows, mines };
-        let cells = vec![vec![CellInternal::default(); rows]; cols];
+        let mut cols = options.cols.clamp(9, 30);
+        let mut rows = options.rows.clamp(9, 30);
+        let layers = options.layers.clamp(1, 9);
+        let mines_per_cell = options.mines_per_cell.clamp(1, 3);
+
+        // wrapped rows and columns have to keep their odd/even shapes
+        if options.topology == Topology::Torus {
+            match options.tiling {
+                Tiling::Square => (),
+                Tiling::Hex => rows += rows % 2,
+                Tiling::Triangle => {
+                    cols += cols % 2;
+                    rows += rows % 2;
+                }
+            }
+        }
+
+        // other tilings have no room for custom rules
+        let neighbourhood = match (options.tiling, options.neighbourhood) {
+            (Tiling::Square, Neighbourhood::Custom(mask)) if mask.is_empty() => {
+                Neighbourhood::Adjacent
+            }
+            (Tiling::Square, neighbourhood) => neighbourhood,
+            _ => Neighbourhood::Adjacent,
+        };
+
+        let first_click = match options.first_click {
+            FirstClick::Radius(radius) => FirstClick::Radius(radius.min(3)),
+            first_click => first_click,
+        };
+
+        let options = FieldOptions {
+            cols,
+            rows,
+            layers,
+            mines_per_cell,
+            neighbourhood,
+            first_click,
+            ..options
+        };
+        let cells = vec![vec![CellInternal::default(); rows]; cols * layers];
+
+        let mut minefield = Self { options, cells };
+
+        let slots = minefield.max_mines();
+        minefield.options.mines = options.mines.clamp(10.min(slots), slots);
+
+        minefield
+    }
 
-        Self { options, cells }
+    // the most mines that fit next to the area kept free on the first click;
+    // that area is the largest in the middle of a full board, so the mines
+    // fit wherever the click lands
+    pub fn max_mines(&self) -> usize {
+        let FieldOptions {
+            cols,
+            rows,
+            layers,
+            mines_per_cell,
+            ..
+        } = self.options;
+
+        let probe = Self {
+            options: FieldOptions {
+                shape: BoardShape::default(),
+                ..self.options
+            },
+            cells: Vec::new(),
+        };
+        let center = (cols * (layers / 2) + cols / 2, rows / 2);
+        let safe_area = probe.get_safe_area(center.0, center.1).len();
+
+        self.options.cells_count().saturating_sub(safe_area) * mines_per_cell as usize
     }
 
     pub fn options(&self) -> FieldOptions {
         self.options
     }
 
+    /// # Safety
+    /// `x` and `y` have to lie on the field
     pub unsafe fn get_cell_unchecked(&self, x: usize, y: usize) -> MineCell {
         let cell = self.cells[x][y];
 
         MineCell { cell, x, y }
     }
 
+    // void cells are left out
     pub fn get_coords_all(&self) -> impl Iterator<Item = (usize, usize)> {
-        let x_range = 0..self.options.cols;
-        let y_range = 0..self.options.rows;
+        let options = self.options;
+        let x_range = 0..options.total_cols();
+        let y_range = 0..options.rows;
 
-        x_range.flat_map(move |x| y_range.clone().map(move |y| (x, y)))
+        x_range
+            .flat_map(move |x| y_range.clone().map(move |y| (x, y)))
+            .filter(move |&(x, y)| !options.is_void(x, y))
     }
 
+    // cells right above and below on the next layers are neighbours too
     pub fn get_coords_around(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
-        let x_range = x.saturating_sub(1)..(x + 2).min(self.options.cols);
-        let y_range = y.saturating_sub(1)..(y + 2).min(self.options.rows);
+        let offsets = self.options.get_offsets(x % self.options.cols, y);
+        self.get_coords_shifted(x, y, offsets)
+    }
+
+    // cells that have the given one as a neighbour, which are the ones around it
+    // unless a custom mask is asymmetric
+    fn get_coords_seeing(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let offsets = match (self.options.tiling, self.options.neighbourhood) {
+            (Tiling::Square, Neighbourhood::Custom(mask)) => mask.reflected(),
+            _ => self.options.get_offsets(x % self.options.cols, y),
+        };
+
+        self.get_coords_shifted(x, y, offsets)
+    }
 
-        x_range.flat_map(move |x| y_range.clone().map(move |y| (x, y)))
+    // the same cell on the layers next to it, and the offsets on all of them
+    fn get_coords_shifted(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: OffsetMask,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let options = self.options;
+        let FieldOptions { cols, layers, .. } = options;
+
+        let (layer, x) = (x / cols, x % cols);
+
+        let layer_range = layer.saturating_sub(1)..(layer + 2).min(layers);
+
+        layer_range.flat_map(move |z| {
+            let center = (z != layer).then_some((0, 0));
+
+            center
+                .into_iter()
+                .chain(offsets.offsets())
+                .filter_map(move |(dx, dy)| options.shift(x, y, dx, dy))
+                .map(move |(x, y)| (x + z * cols, y))
+                .filter(move |&(x, y)| !options.is_void(x, y))
+        })
     }
 
-    pub fn count_flags_around(&self, x: usize, y: usize) -> u8 {
+    pub fn count_flags_around(&self, x: usize, y: usize) -> u16 {
         self.get_coords_around(x, y)
-            .filter(|&(x, y)| self.cells[x][y].state == CellState::Flagged)
-            .count() as u8
+            .map(|(x, y)| match self.cells[x][y].state {
+                CellState::Flagged(n) => n as u16,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn count_hidden_safe_cells(&self) -> usize {
+        self.get_coords_all()
+            .map(|(x, y)| self.cells[x][y])
+            .filter(|cell| matches!(cell.kind, CellKind::Num(_)))
+            .filter(|cell| cell.state != CellState::Revealed)
+            .count()
+    }
+
+    // moves the hidden mines before a hidden cell is opened, the way the luck
+    // of the field decides; the area kept free around the first click stays free
+    // and nothing moves before the first cell is revealed;
+    // returns whether the layout has changed
+    pub fn apply_luck(&mut self, x: usize, y: usize, first_click: (usize, usize)) -> bool {
+        let is_mined = matches!(self.cells[x][y].kind, CellKind::Mined(_));
+
+        let started = self
+            .cells
+            .iter()
+            .flatten()
+            .any(|cell| cell.state == CellState::Revealed);
+
+        if !started {
+            return false;
+        }
+
+        let safe_area = self.get_safe_area(first_click.0, first_click.1);
+
+        let layout = match self.options.luck {
+            Luck::Fixed => None,
+            // only a forced guess is saved, when nothing could be proved safe
+            Luck::Kind if is_mined => {
+                let forced = !self.has_provable_safe_cell();
+
+                forced
+                    .then(|| solver::find_layout(self, (x, y), false, &safe_area))
+                    .flatten()
+            }
+            Luck::Mean if !is_mined => solver::find_layout(self, (x, y), true, &safe_area),
+            _ => None,
+        };
+
+        let Some(layout) = layout else {
+            return false;
+        };
+
+        self.clear_mines();
+        self.set_mines(&layout);
+
+        true
+    }
+
+    // whether the revealed numbers prove a hidden cell safe, with every rule
+    // applied until nothing new comes up
+    fn has_provable_safe_cell(&self) -> bool {
+        let mut solver = Solver::new(self);
+        solver.solve();
+
+        self.get_coords_all().any(|(x, y)| {
+            self.cells[x][y].state != CellState::Revealed
+                && solver.knowledge(x, y) == Knowledge::Safe
+        })
     }
 
     pub fn set_cell(&mut self, cell: &MineCell) {
@@ -107,35 +315,681 @@ impl MineField {
             .for_each(|cell| *cell = CellInternal::default());
     }
 
-    pub unsafe fn place_random_mines(&mut self, init_x: usize, init_y: usize) {
-        use rand::{rng, seq::index::sample};
+    // cells kept free of mines around the first click, found by walking
+    // through the neighbours as many steps as the policy allows
+    fn get_safe_area(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let steps = match self.options.first_click {
+            FirstClick::Cell => 0,
+            FirstClick::Opening => 1,
+            FirstClick::Radius(radius) => radius,
+        };
+
+        let mut area = vec![(x, y)];
+        let mut start = 0;
 
-        let FieldOptions { cols, rows, mines } = self.options;
+        for _ in 0..steps {
+            let end = area.len();
 
-        let init_index = init_x + init_y * cols;
+            for index in start..end {
+                let (x, y) = area[index];
 
-        sample(&mut rng(), cols * rows - 1, mines)
-            .into_iter()
-            .for_each(|mut index| {
-                if index >= init_index {
-                    index += 1;
+                for coords in self.get_coords_around(x, y) {
+                    if !area.contains(&coords) {
+                        area.push(coords);
+                    }
                 }
+            }
 
-                let x = index % cols;
-                let y = index / cols;
+            start = end;
+        }
 
-                self.cells[x][y].kind = CellKind::Mined;
+        area
+    }
+
+    // returns the number of mines actually placed, which is up to the strategy
+    pub fn place_mines(
+        &mut self,
+        init_x: usize,
+        init_y: usize,
+        placement: &mut dyn MinePlacement,
+    ) -> usize {
+        self.place_mines_timed(init_x, init_y, placement, &SystemClock::default())
+    }
+
+    // same as place_mines, with the time budget of rated boards read from the clock
+    pub fn place_mines_timed(
+        &mut self,
+        init_x: usize,
+        init_y: usize,
+        placement: &mut dyn MinePlacement,
+        clock: &dyn Clock,
+    ) -> usize {
+        let safe_area = self.get_safe_area(init_x, init_y);
+
+        let free: Vec<_> = self
+            .get_coords_all()
+            .filter(|coords| !safe_area.contains(coords))
+            .collect();
+
+        if self.options.difficulty == Difficulty::Any {
+            let mines = placement.place(self, &free);
+            return self.set_mines(&mines);
+        }
+
+        // boards are drawn until one is rated as asked, the closest one is kept
+        // when the attempts or the time run out; a seeded placement gives the
+        // same one as long as the time budget isn't reached
+        let deadline = clock.now() + Difficulty::TIME_BUDGET;
+        let mut best: Option<(u32, Vec<_>)> = None;
+
+        for _ in 0..Difficulty::ATTEMPTS {
+            let mines = placement.place(self, &free);
+
+            self.set_mines(&mines);
+            let rating = self.rate(init_x, init_y);
+            self.clear_mines();
+
+            let distance = self.options.difficulty.distance(&rating);
+
+            if best.as_ref().is_none_or(|(best, _)| distance < *best) {
+                best = Some((distance, mines));
+            }
+
+            if distance == 0 || clock.now() >= deadline {
+                break;
+            }
+        }
+
+        let mines = best.map(|(_, mines)| mines).unwrap_or_default();
+
+        self.set_mines(&mines)
+    }
+
+    fn clear_mines(&mut self) {
+        for cell in self.cells.iter_mut().flatten() {
+            cell.kind = CellKind::default();
+        }
+    }
+
+    // mines on void cells, off the board or above the capacity of a cell are dropped
+    fn set_mines(&mut self, mines: &[(usize, usize)]) -> usize {
+        let FieldOptions {
+            rows,
+            mines_per_cell,
+            ..
+        } = self.options;
+        let cols = self.options.total_cols();
+
+        let mut placed = 0;
 
-                for (x, y) in self.get_coords_around(x, y) {
-                    self.cells[x][y].kind.increment()
+        for &(x, y) in mines {
+            if x >= cols || y >= rows || self.options.is_void(x, y) {
+                continue;
+            }
+
+            let kind = &mut self.cells[x][y].kind;
+
+            if let CellKind::Mined(n) = kind {
+                if *n >= mines_per_cell {
+                    continue;
                 }
-            });
+            }
+
+            kind.add_mine();
+            placed += 1;
+
+            for (x, y) in self.get_coords_seeing(x, y) {
+                self.cells[x][y].kind.increment()
+            }
+        }
+
+        placed
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Default, Clone, Copy)]
 pub struct FieldOptions {
     pub cols: usize,
     pub rows: usize,
+    pub layers: usize,
     pub mines: usize,
+    // above 1 for the multimines variant
+    pub mines_per_cell: u8,
+    // void cells, the same on every layer
+    pub shape: BoardShape,
+    pub first_click: FirstClick,
+    pub placement: Placement,
+    pub luck: Luck,
+    pub difficulty: Difficulty,
+    pub tiling: Tiling,
+    pub topology: Topology,
+    pub neighbourhood: Neighbourhood,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Tiling {
+    #[default]
+    Square,
+    // pointy-top hexagons, odd rows are shifted right by half a cell
+    Hex,
+    // triangles pointing up where x + y is even and down elsewhere
+    Triangle,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum FirstClick {
+    // only the clicked cell is free of mines
+    #[default]
+    Cell,
+    // the clicked cell and its neighbours, so the first click opens a zero
+    Opening,
+    // every cell up to the given number of neighbour steps away (at most 3)
+    Radius(u8),
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    #[default]
+    Any,
+    // solvable without guesses using single numbers only
+    EasyLogic,
+    // solvable without guesses, but only with the subset rule
+    Advanced,
+    // rating score within the given bounds
+    Score(u32, u32),
+}
+
+impl Difficulty {
+    const ATTEMPTS: usize = 50;
+    const TIME_BUDGET: Duration = Duration::from_secs(2);
+
+    // 0 when the rating is what was asked for, grows the further it is
+    pub fn distance(self, rating: &Rating) -> u32 {
+        let level = rating.level as u32;
+
+        match self {
+            Self::Any => 0,
+            Self::EasyLogic => rating.guesses * 2 + level.saturating_sub(1),
+            Self::Advanced => rating.guesses * 2 + 2u32.saturating_sub(level),
+            Self::Score(min, max) => {
+                min.saturating_sub(rating.score) + rating.score.saturating_sub(max)
+            }
+        }
+    }
+}
+
+// the mines of a lazy field keep moving behind the scenes, but never
+// against the numbers already revealed
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Luck {
+    // the layout is decided on the first click once and for all
+    #[default]
+    Fixed,
+    // a forced guess never hits a mine, when the numbers allow it
+    Kind,
+    // a guess always hits a mine, when the numbers allow it
+    Mean,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Topology {
+    #[default]
+    Plane,
+    // left/right and top/bottom edges are glued together
+    Torus,
+}
+
+impl FieldOptions {
+    // columns of all layers together
+    pub fn total_cols(&self) -> usize {
+        self.cols * self.layers
+    }
+
+    // usable cells only
+    pub fn cells_count(&self) -> usize {
+        let void = self.shape.count_void(self.cols, self.rows);
+
+        (self.cols * self.rows - void) * self.layers
+    }
+
+    pub fn is_void(&self, x: usize, y: usize) -> bool {
+        self.shape.is_void(x % self.cols, y)
+    }
+
+    // offsets of the neighbours of the given cell
+    pub fn get_offsets(&self, x: usize, y: usize) -> OffsetMask {
+        self.neighbourhood.get_mask(self.tiling, x, y)
+    }
+
+    // moves the given coords, returns None when falling off the board
+    pub fn shift(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let cols = self.cols as isize;
+        let rows = self.rows as isize;
+
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+
+        match self.topology {
+            Topology::Plane if (0..cols).contains(&x) && (0..rows).contains(&y) => {
+                Some((x as usize, y as usize))
+            }
+            Topology::Plane => None,
+            Topology::Torus => Some((x.rem_euclid(cols) as usize, y.rem_euclid(rows) as usize)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::clock::ManualClock;
+
+    fn seeded_field(luck: Luck, first_click: FirstClick, seed: u64) -> MineField {
+        let mut field = MineField::new(FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 1,
+            mines: 10,
+            mines_per_cell: 1,
+            first_click,
+            luck,
+            ..Default::default()
+        });
+
+        field.place_mines(4, 4, &mut SeededPlacement::new(seed));
+        field
+    }
+
+    // a 9x9 field holding the given mines, with every cell but the hidden ones revealed
+    pub(super) fn field_with_hidden(
+        mines_per_cell: u8,
+        mines: &[(usize, usize)],
+        hidden: impl Fn(usize, usize) -> bool,
+    ) -> MineField {
+        let mut field = MineField::new(FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 1,
+            mines_per_cell,
+            ..Default::default()
+        });
+
+        field.set_mines(mines);
+        field.options.mines = field.count_mines();
+
+        for (x, y) in field.get_coords_all() {
+            if !hidden(x, y) {
+                field.cells[x][y].state = CellState::Revealed;
+            }
+        }
+
+        field
+    }
+
+    // every layout agreeing with the revealed numbers and the mine count, found by
+    // trying all of them; each one sorted, with an entry per mine
+    pub(super) fn brute_force_layouts(field: &MineField) -> Vec<Vec<(usize, usize)>> {
+        let hidden: Vec<_> = field
+            .get_coords_all()
+            .filter(|&(x, y)| field.cells[x][y].state != CellState::Revealed)
+            .collect();
+
+        let mut layouts = Vec::new();
+        let mut values = Vec::new();
+
+        try_values(
+            field,
+            &hidden,
+            field.count_mines(),
+            &mut values,
+            &mut layouts,
+        );
+        layouts
+    }
+
+    fn try_values(
+        field: &MineField,
+        hidden: &[(usize, usize)],
+        left: usize,
+        values: &mut Vec<usize>,
+        layouts: &mut Vec<Vec<(usize, usize)>>,
+    ) {
+        if values.len() == hidden.len() {
+            let mut mines: Vec<_> = hidden
+                .iter()
+                .zip(values.iter())
+                .flat_map(|(&coords, &value)| std::iter::repeat_n(coords, value))
+                .collect();
+
+            let agrees = field.get_coords_all().all(|(x, y)| {
+                let cell = field.cells[x][y];
+
+                cell.state != CellState::Revealed
+                    || cell.kind.to_int() as usize
+                        == field
+                            .get_coords_around(x, y)
+                            .map(|coords| mines.iter().filter(|&&mine| mine == coords).count())
+                            .sum::<usize>()
+            });
+
+            if left == 0 && agrees {
+                mines.sort();
+                layouts.push(mines);
+            }
+
+            return;
+        }
+
+        let per_cell = field.options.mines_per_cell as usize;
+
+        for value in 0..=per_cell.min(left) {
+            values.push(value);
+            try_values(field, hidden, left - value, values, layouts);
+            values.pop();
+        }
+    }
+
+    fn is_mined(field: &MineField, (x, y): (usize, usize)) -> bool {
+        matches!(field.cells[x][y].kind, CellKind::Mined(_))
+    }
+
+    // the numbers already shown and the mine count stay as they were
+    fn assert_agrees(before: &MineField, after: &MineField) {
+        assert_eq!(before.count_mines(), after.count_mines());
+
+        for (x, y) in before.get_coords_all() {
+            if before.cells[x][y].state == CellState::Revealed {
+                assert!(before.cells[x][y].kind == after.cells[x][y].kind);
+            }
+        }
+    }
+
+    #[test]
+    fn first_click_is_left_alone() {
+        for seed in 0..20 {
+            let mut field = seeded_field(Luck::Mean, FirstClick::Opening, seed);
+
+            assert!(!field.apply_luck(4, 4, (4, 4)));
+            assert!(!is_mined(&field, (4, 4)));
+        }
+    }
+
+    #[test]
+    fn mean_luck_keeps_the_safe_area_free() {
+        let mut moved = 0;
+
+        for seed in 0..20 {
+            let mut field = seeded_field(Luck::Mean, FirstClick::Radius(2), seed);
+            field.reveal_from(4, 4);
+
+            let safe_area = field.get_safe_area(4, 4);
+
+            for coords in field.get_coords_all() {
+                let (x, y) = coords;
+
+                if field.cells[x][y].state != CellState::Hidden || is_mined(&field, coords) {
+                    continue;
+                }
+
+                let mut lucky = field.clone();
+
+                if lucky.apply_luck(x, y, (4, 4)) {
+                    moved += 1;
+                    assert!(is_mined(&lucky, coords));
+                }
+
+                assert!(safe_area.iter().all(|&cell| !is_mined(&lucky, cell)));
+                assert_agrees(&field, &lucky);
+            }
+        }
+
+        assert!(moved > 0);
+    }
+
+    // the position after the first click, then after each round of cells
+    // a single step of the solver proves safe, while there are any
+    fn kind_positions(seed: u64) -> Vec<MineField> {
+        let mut field = seeded_field(Luck::Kind, FirstClick::Opening, seed);
+        field.reveal_from(4, 4);
+
+        let mut positions = vec![field.clone()];
+
+        for _ in 0..4 {
+            let safe: Vec<_> = Solver::new(&field)
+                .deduce()
+                .into_iter()
+                .filter(|deduction| deduction.knowledge == Knowledge::Safe)
+                .collect();
+
+            if safe.is_empty() {
+                break;
+            }
+
+            for deduction in safe {
+                let (x, y) = deduction.coords;
+                field.reveal_from(x, y);
+            }
+
+            positions.push(field.clone());
+        }
+
+        positions
+    }
+
+    // no hidden cell is proved safe once the solver has nothing left to find
+    fn is_forced(field: &MineField) -> bool {
+        let mut solver = Solver::new(field);
+        solver.solve();
+
+        field.get_coords_all().all(|(x, y)| {
+            field.cells[x][y].state == CellState::Revealed
+                || solver.knowledge(x, y) != Knowledge::Safe
+        })
+    }
+
+    fn saved_guesses(field: &MineField) -> usize {
+        let mut saved = 0;
+
+        for coords in field.get_coords_all() {
+            let (x, y) = coords;
+
+            if field.cells[x][y].state != CellState::Hidden || !is_mined(field, coords) {
+                continue;
+            }
+
+            let mut lucky = field.clone();
+
+            if lucky.apply_luck(x, y, (4, 4)) {
+                saved += 1;
+                assert!(!is_mined(&lucky, coords));
+                assert_agrees(field, &lucky);
+            }
+        }
+
+        saved
+    }
+
+    #[test]
+    fn kind_luck_saves_forced_guesses() {
+        let mut saved = 0;
+
+        for seed in 0..50 {
+            for field in kind_positions(seed) {
+                let count = saved_guesses(&field);
+
+                assert!(count == 0 || is_forced(&field));
+                saved += count;
+            }
+        }
+
+        assert!(saved > 0);
+    }
+
+    #[test]
+    fn kind_luck_leaves_guesses_the_subset_rule_avoids() {
+        let mut checked = 0;
+
+        for seed in 0..20 {
+            for field in kind_positions(seed) {
+                let single_only = Solver::new(&field)
+                    .deduce()
+                    .iter()
+                    .all(|deduction| deduction.knowledge != Knowledge::Safe);
+
+                if single_only && !is_forced(&field) {
+                    checked += 1;
+                    assert_eq!(saved_guesses(&field), 0);
+                }
+            }
+        }
+
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn numbers_count_the_mines_a_cell_sees() {
+        // every cell sees the one to its right and the one two rows below it
+        let mask = OffsetMask::from_offsets(&[(1, 0), (0, 2)]);
+
+        let mut field = MineField::new(FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 2,
+            mines: 10,
+            mines_per_cell: 1,
+            neighbourhood: Neighbourhood::Custom(mask),
+            ..Default::default()
+        });
+
+        field.place_mines(0, 0, &mut SeededPlacement::new(7));
+
+        for (x, y) in field.get_coords_all() {
+            let CellKind::Num(number) = field.cells[x][y].kind else {
+                continue;
+            };
+
+            let seen = field
+                .get_coords_around(x, y)
+                .filter(|&coords| is_mined(&field, coords))
+                .count();
+
+            assert_eq!(number as usize, seen);
+        }
+    }
+
+    #[test]
+    fn numbers_fit_the_largest_neighbourhood() {
+        // the full 7x7 window on three layers, three mines in every neighbour
+        let mut field = MineField::new(FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 3,
+            mines_per_cell: 3,
+            neighbourhood: Neighbourhood::Custom(OffsetMask::square(OffsetMask::RADIUS)),
+            ..Default::default()
+        });
+
+        let center = (9 + 4, 4);
+        let around: Vec<_> = field.get_coords_around(center.0, center.1).collect();
+        let mines: Vec<_> = around.iter().flat_map(|&coords| [coords; 3]).collect();
+
+        assert_eq!(around.len(), 48 + 49 * 2);
+        assert_eq!(field.set_mines(&mines), mines.len());
+
+        for &(x, y) in &around {
+            field.cells[x][y].state = CellState::Flagged(3);
+        }
+
+        assert!(field.cells[center.0][center.1].kind == CellKind::Num(438));
+        assert_eq!(field.count_flags_around(center.0, center.1), 438);
+    }
+
+    // a seeded placement that takes a second of the clock for every board
+    struct SlowPlacement<'a> {
+        clock: &'a ManualClock,
+        seeded: SeededPlacement,
+        boards: Vec<Vec<(usize, usize)>>,
+    }
+
+    impl MinePlacement for SlowPlacement<'_> {
+        fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+            self.clock.advance(Duration::from_secs(1));
+            let mines = self.seeded.place(field, free);
+            self.boards.push(mines.clone());
+            mines
+        }
+    }
+
+    #[test]
+    fn rated_placement_stops_at_the_time_budget() {
+        let options = FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 1,
+            mines: 12,
+            mines_per_cell: 1,
+            // no board can reach that score, so only the time ends the search
+            difficulty: Difficulty::Score(u32::MAX, u32::MAX),
+            ..Default::default()
+        };
+
+        let clock = ManualClock::default();
+        clock.set(Duration::from_secs(60));
+
+        let mut placement = SlowPlacement {
+            clock: &clock,
+            seeded: SeededPlacement::new(3),
+            boards: Vec::new(),
+        };
+
+        let mut field = MineField::new(options);
+        field.place_mines_timed(4, 4, &mut placement, &clock);
+
+        let budget = Difficulty::TIME_BUDGET.as_secs() as usize;
+        assert_eq!(placement.boards.len(), budget);
+
+        // the closest of the boards drawn in time is the one kept
+        let distance = |mines: &[(usize, usize)]| {
+            let mut field = MineField::new(options);
+            field.set_mines(mines);
+            options.difficulty.distance(&field.rate(4, 4))
+        };
+        let closest = placement
+            .boards
+            .iter()
+            .min_by_key(|mines| distance(mines))
+            .map(|mines| distance(mines));
+
+        let placed: Vec<_> = field
+            .get_coords_all()
+            .filter(|&coords| is_mined(&field, coords))
+            .collect();
+        assert_eq!(Some(distance(&placed)), closest);
+    }
+
+    #[test]
+    fn seeded_difficulty_boards_repeat() {
+        let layout = |seed| {
+            let mut field = MineField::new(FieldOptions {
+                cols: 9,
+                rows: 9,
+                layers: 1,
+                mines: 12,
+                mines_per_cell: 1,
+                difficulty: Difficulty::EasyLogic,
+                ..Default::default()
+            });
+
+            field.place_mines(4, 4, &mut SeededPlacement::new(seed));
+
+            field
+                .get_coords_all()
+                .filter(|&coords| is_mined(&field, coords))
+                .collect::<Vec<_>>()
+        };
+
+        for seed in 0..5 {
+            assert_eq!(layout(seed), layout(seed));
+        }
+    }
 }
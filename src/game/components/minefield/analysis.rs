@@ -0,0 +1,294 @@
+use super::{
+    solver::{Knowledge, Solver},
+    CellKind, CellState, MineField,
+};
+
+// what the player did, in the order it was done
+#[derive(Clone, Copy)]
+pub enum Move {
+    Open(usize, usize),
+    // opening the hidden cells around a number with all of its flags
+    Chord(usize, usize),
+    // the number of flags left on the cell
+    Flag(usize, usize, u8),
+}
+
+// a move of the replay, with the game time it was made at
+#[derive(Clone, Copy)]
+pub struct TimedMove {
+    pub step: Move,
+    pub millis: u64,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Verdict {
+    // the cell was proved safe by the numbers
+    Forced,
+    // a guess while some other cell was proved safe, with the chance of a mine
+    NeedlessGuess(Option<f64>),
+    // nothing could be proved, with the chance of a mine
+    Guess(Option<f64>),
+    // the cell was proved to be mined
+    Blunder,
+    // more flags than mines
+    WrongFlag,
+}
+
+pub struct MoveReport {
+    pub x: usize,
+    pub y: usize,
+    pub millis: u64,
+    pub verdict: Verdict,
+    // the click that set a mine off
+    pub fatal: bool,
+}
+
+#[derive(Default)]
+pub struct GameAnalysis {
+    pub moves: Vec<MoveReport>,
+}
+
+impl GameAnalysis {
+    pub fn fatal(&self) -> Option<&MoveReport> {
+        self.moves.iter().find(|report| report.fatal)
+    }
+
+    // a safe cell could be proved when the mine went off
+    pub fn is_loss_avoidable(&self) -> Option<bool> {
+        let fatal = self.fatal()?;
+
+        Some(matches!(
+            fatal.verdict,
+            Verdict::NeedlessGuess(_) | Verdict::Blunder
+        ))
+    }
+}
+
+impl MineField {
+    // replays the moves on the current layout; the first click is left out
+    // as it can't go wrong, and on a lucky field the layout may have moved
+    // during the game, so earlier moves are judged against its final state
+    pub fn analyse_game(&self, moves: &[TimedMove]) -> GameAnalysis {
+        let mut scratch = Self {
+            options: self.options,
+            cells: self.cells.clone(),
+        };
+
+        for cell in scratch.cells.iter_mut().flatten() {
+            cell.state = CellState::Hidden;
+        }
+
+        let mut analysis = GameAnalysis::default();
+        let mut started = false;
+
+        for &TimedMove { step, millis } in moves {
+            let opened = match step {
+                Move::Open(x, y) => vec![(x, y)],
+                Move::Chord(x, y) => scratch
+                    .get_coords_around(x, y)
+                    .filter(|&(x, y)| scratch.cells[x][y].state == CellState::Hidden)
+                    .collect(),
+                Move::Flag(x, y, flags) => {
+                    scratch.cells[x][y].state = match flags {
+                        0 => CellState::Hidden,
+                        flags => CellState::Flagged(flags),
+                    };
+
+                    let mines = match scratch.cells[x][y].kind {
+                        CellKind::Mined(n) => n,
+                        CellKind::Num(_) => 0,
+                    };
+
+                    if flags > mines {
+                        analysis.moves.push(MoveReport {
+                            x,
+                            y,
+                            millis,
+                            verdict: Verdict::WrongFlag,
+                            fatal: false,
+                        });
+                    }
+
+                    continue;
+                }
+            };
+
+            for (x, y) in opened {
+                if scratch.cells[x][y].state != CellState::Hidden {
+                    continue;
+                }
+
+                if started {
+                    analysis.moves.push(MoveReport {
+                        x,
+                        y,
+                        millis,
+                        verdict: scratch.judge_opening(x, y),
+                        fatal: matches!(scratch.cells[x][y].kind, CellKind::Mined(_)),
+                    });
+                }
+
+                scratch.reveal_from(x, y);
+                started = true;
+            }
+        }
+
+        analysis
+    }
+
+    // a text report of the analysis, one line per judged move and a summary
+    pub fn describe_analysis(&self, analysis: &GameAnalysis) -> Vec<String> {
+        let percent = |chance: Option<f64>| match chance {
+            Some(chance) => format!("{:.0}% mine", chance * 100.0),
+            None => String::from("mine chance unknown"),
+        };
+
+        let mut lines: Vec<_> = analysis
+            .moves
+            .iter()
+            .map(|report| {
+                let cell = format!(
+                    "{}.{:03} s {}",
+                    report.millis / 1000,
+                    report.millis % 1000,
+                    self.format_coords((report.x, report.y))
+                );
+
+                match report.verdict {
+                    Verdict::Forced => format!("{cell} safe click, proved by the numbers"),
+                    Verdict::NeedlessGuess(chance) => format!(
+                        "{cell} needless guess ({}), another cell was proved safe",
+                        percent(chance)
+                    ),
+                    Verdict::Guess(chance) => {
+                        format!("{cell} forced guess ({})", percent(chance))
+                    }
+                    Verdict::Blunder => format!("{cell} opened although proved to be a mine"),
+                    Verdict::WrongFlag => format!("{cell} wrong flag"),
+                }
+            })
+            .collect();
+
+        let count = |verdict: fn(&Verdict) -> bool| {
+            analysis
+                .moves
+                .iter()
+                .filter(|report| verdict(&report.verdict))
+                .count()
+        };
+
+        lines.push(format!(
+            "{} safe clicks, {} forced guesses, {} needless guesses, {} blunders, {} wrong flags",
+            count(|v| *v == Verdict::Forced),
+            count(|v| matches!(v, Verdict::Guess(_))),
+            count(|v| matches!(v, Verdict::NeedlessGuess(_))),
+            count(|v| *v == Verdict::Blunder),
+            count(|v| *v == Verdict::WrongFlag),
+        ));
+
+        if let (Some(fatal), Some(avoidable)) = (analysis.fatal(), analysis.is_loss_avoidable()) {
+            let cell = self.format_coords((fatal.x, fatal.y));
+
+            lines.push(if avoidable {
+                format!("the fatal click at {cell} could have been avoided")
+            } else {
+                format!("the fatal click at {cell} was a forced guess")
+            });
+        }
+
+        lines
+    }
+
+    fn judge_opening(&self, x: usize, y: usize) -> Verdict {
+        let mut solver = Solver::new(self);
+        solver.solve();
+
+        match solver.knowledge(x, y) {
+            Knowledge::Safe => return Verdict::Forced,
+            Knowledge::Mined => return Verdict::Blunder,
+            Knowledge::Unknown => (),
+        }
+
+        let chance = self
+            .get_probabilities()
+            .map(|probabilities| probabilities[x][y]);
+
+        let safe_known = self.get_coords_all().any(|(x, y)| {
+            self.cells[x][y].state != CellState::Revealed
+                && solver.knowledge(x, y) == Knowledge::Safe
+        });
+
+        if safe_known {
+            Verdict::NeedlessGuess(chance)
+        } else {
+            Verdict::Guess(chance)
+        }
+    }
+
+    // opens a cell the way a click does, zeros open their neighbours
+    pub(super) fn reveal_from(&mut self, x: usize, y: usize) {
+        let mut stack = vec![(x, y)];
+
+        while let Some((x, y)) = stack.pop() {
+            let cell = &mut self.cells[x][y];
+
+            if cell.state != CellState::Hidden {
+                continue;
+            }
+
+            cell.state = CellState::Revealed;
+
+            if cell.kind == CellKind::Num(0) {
+                stack.extend(self.get_coords_around(x, y));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FieldOptions;
+    use super::*;
+    use crate::game::{clock::ManualClock, components::counters::SecsCounter};
+    use std::{rc::Rc, time::Duration};
+
+    #[test]
+    fn reports_keep_the_time_of_their_moves() {
+        let mut field = MineField::new(FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 1,
+            mines: 9,
+            mines_per_cell: 1,
+            ..Default::default()
+        });
+        let wall: Vec<_> = (0..9).map(|y| (4, y)).collect();
+        field.set_mines(&wall);
+
+        let clock = Rc::new(ManualClock::default());
+        let mut counter = SecsCounter::new(clock.clone());
+        let mut moves = Vec::new();
+
+        counter.start();
+        let mut record = |step, after| {
+            clock.advance(Duration::from_millis(after));
+            moves.push(TimedMove {
+                step,
+                millis: counter.elapsed_millis(),
+            });
+        };
+
+        record(Move::Open(0, 0), 0);
+        record(Move::Flag(6, 6, 1), 1_500);
+        record(Move::Open(8, 8), 750);
+
+        let analysis = field.analyse_game(&moves);
+        let times: Vec<_> = analysis
+            .moves
+            .iter()
+            .map(|report| (report.x, report.y, report.millis))
+            .collect();
+
+        assert_eq!(times, [(6, 6, 1_500), (8, 8, 2_250)]);
+    }
+}
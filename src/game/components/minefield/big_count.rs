@@ -0,0 +1,358 @@
+use rand::Rng;
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+// unsigned integer of any size for counting layouts,
+// little-endian 32-bit limbs without trailing zeros
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct BigCount(Vec<u32>);
+
+impl BigCount {
+    pub fn zero() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn one() -> Self {
+        Self::from(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // n! / (k! (n - k)!)
+    pub fn binomial(n: usize, k: usize) -> Self {
+        if k > n {
+            return Self::zero();
+        }
+
+        let k = k.min(n - k);
+        let mut result = Self::one();
+
+        // every partial product is a binomial itself, so the division is exact
+        for i in 1..=k {
+            result = result.mul_small((n - k + i) as u32);
+            result = result.div_small(i as u32).0;
+        }
+
+        result
+    }
+
+    // natural logarithm, good to the precision of f64; -inf for zero
+    pub fn ln(&self) -> f64 {
+        let len = self.0.len();
+
+        let top = self
+            .0
+            .iter()
+            .rev()
+            .take(2)
+            .fold(0.0, |top, &limb| top * 4_294_967_296.0 + limb as f64);
+
+        let shift = len.saturating_sub(2) as f64 * 32.0;
+
+        top.ln() + shift * std::f64::consts::LN_2
+    }
+
+    // uniformly drawn from 0..self, zero for zero
+    pub fn random_below(&self, rng: &mut impl Rng) -> Self {
+        let Some(&top) = self.0.last() else {
+            return Self::zero();
+        };
+
+        let mask = u32::MAX >> top.leading_zeros();
+
+        loop {
+            let mut limbs: Vec<u32> = (0..self.0.len()).map(|_| rng.random()).collect();
+
+            if let Some(last) = limbs.last_mut() {
+                *last &= mask;
+            }
+
+            let candidate = Self(limbs).normalized();
+
+            if candidate < *self {
+                return candidate;
+            }
+        }
+    }
+
+    fn mul_small(&self, factor: u32) -> Self {
+        let mut carry = 0u64;
+        let mut limbs = Vec::with_capacity(self.0.len() + 1);
+
+        for &limb in &self.0 {
+            let value = limb as u64 * factor as u64 + carry;
+            limbs.push(value as u32);
+            carry = value >> 32;
+        }
+
+        limbs.push(carry as u32);
+
+        Self(limbs).normalized()
+    }
+
+    // returns the quotient and the remainder
+    fn div_small(&self, divisor: u32) -> (Self, u32) {
+        let mut remainder = 0u64;
+        let mut limbs = vec![0; self.0.len()];
+
+        for (i, &limb) in self.0.iter().enumerate().rev() {
+            let value = (remainder << 32) | limb as u64;
+            limbs[i] = (value / divisor as u64) as u32;
+            remainder = value % divisor as u64;
+        }
+
+        (Self(limbs).normalized(), remainder as u32)
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+
+        self
+    }
+}
+
+impl From<u64> for BigCount {
+    fn from(value: u64) -> Self {
+        Self(vec![value as u32, (value >> 32) as u32]).normalized()
+    }
+}
+
+impl Ord for BigCount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigCount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigCount {
+    type Output = BigCount;
+
+    fn add(self, other: Self) -> BigCount {
+        let (long, short) = if self.0.len() >= other.0.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let mut carry = 0u64;
+        let mut limbs = Vec::with_capacity(long.0.len() + 1);
+
+        for (i, &limb) in long.0.iter().enumerate() {
+            let value = limb as u64 + *short.0.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push(value as u32);
+            carry = value >> 32;
+        }
+
+        limbs.push(carry as u32);
+
+        BigCount(limbs).normalized()
+    }
+}
+
+// saturates at zero
+impl Sub for &BigCount {
+    type Output = BigCount;
+
+    fn sub(self, other: Self) -> BigCount {
+        if self <= other {
+            return BigCount::zero();
+        }
+
+        let mut borrow = 0i64;
+        let mut limbs = Vec::with_capacity(self.0.len());
+
+        for (i, &limb) in self.0.iter().enumerate() {
+            let mut value = limb as i64 - *other.0.get(i).unwrap_or(&0) as i64 - borrow;
+            borrow = 0;
+
+            if value < 0 {
+                value += 1 << 32;
+                borrow = 1;
+            }
+
+            limbs.push(value as u32);
+        }
+
+        BigCount(limbs).normalized()
+    }
+}
+
+impl Mul for &BigCount {
+    type Output = BigCount;
+
+    fn mul(self, other: Self) -> BigCount {
+        let mut limbs = vec![0u32; self.0.len() + other.0.len()];
+
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, &b) in other.0.iter().enumerate() {
+                let value = limbs[i + j] as u64 + a as u64 * b as u64 + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> 32;
+            }
+
+            limbs[i + other.0.len()] = carry as u32;
+        }
+
+        BigCount(limbs).normalized()
+    }
+}
+
+impl fmt::Display for BigCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // nine decimal digits at a time
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_small(1_000_000_000);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        let mut chunks = chunks.iter().rev();
+
+        if let Some(first) = chunks.next() {
+            write!(f, "{first}")?;
+        }
+
+        for chunk in chunks {
+            write!(f, "{chunk:09}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    // exact up to 128 bits, checked through the decimal form
+    fn big(value: u128) -> BigCount {
+        let high = BigCount::from((value >> 64) as u64);
+        let low = BigCount::from(value as u64);
+        let shift = &BigCount::from(1 << 32) * &BigCount::from(1 << 32);
+
+        &(&high * &shift) + &low
+    }
+
+    fn pascal(rows: usize) -> Vec<Vec<u128>> {
+        let mut table: Vec<Vec<u128>> = vec![vec![1]];
+
+        for n in 1..rows {
+            let last = &table[n - 1];
+            let mut row = vec![1; n + 1];
+
+            for k in 1..n {
+                row[k] = last[k - 1] + last[k];
+            }
+
+            table.push(row);
+        }
+
+        table
+    }
+
+    #[test]
+    fn binomials_match_pascal() {
+        for (n, row) in pascal(120).iter().enumerate() {
+            for (k, &value) in row.iter().enumerate() {
+                assert_eq!(BigCount::binomial(n, k).to_string(), value.to_string());
+            }
+
+            assert!(BigCount::binomial(n, n + 1).is_zero());
+        }
+    }
+
+    #[test]
+    fn arithmetic_matches_u128() {
+        let values = [
+            0,
+            1,
+            u32::MAX as u128,
+            1 << 32,
+            u64::MAX as u128,
+            123_456_789_012_345_678,
+            (u64::MAX as u128) << 40 | 77,
+        ];
+
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (big(a), big(b));
+
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!((&x + &y).to_string(), sum.to_string());
+                }
+
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!((&x * &y).to_string(), product.to_string());
+                }
+
+                assert_eq!((&x - &y).to_string(), a.saturating_sub(b).to_string());
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn logarithms_match_f64() {
+        assert_eq!(BigCount::zero().ln(), f64::NEG_INFINITY);
+
+        for n in [1u64, 2, 1000, u32::MAX as u64 + 5, u64::MAX] {
+            assert!((BigCount::from(n).ln() - (n as f64).ln()).abs() < 1e-9);
+        }
+
+        let huge = BigCount::binomial(1000, 500);
+        let expected = (1..=500)
+            .map(|i| ((500 + i) as f64 / i as f64).ln())
+            .sum::<f64>();
+
+        assert!((huge.ln() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn random_draws_stay_below() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert!(BigCount::zero().random_below(&mut rng).is_zero());
+
+        for bound in [big(1), big(3), big(1 << 32), BigCount::binomial(200, 100)] {
+            for _ in 0..200 {
+                assert!(bound.random_below(&mut rng) < bound);
+            }
+        }
+
+        // every value of a small bound comes up
+        let bound = big(5);
+        let mut seen = [false; 5];
+
+        for _ in 0..200 {
+            let value = bound.random_below(&mut rng).to_string();
+            seen[value.parse::<usize>().unwrap()] = true;
+        }
+
+        assert!(seen.iter().all(|&seen| seen));
+    }
+}
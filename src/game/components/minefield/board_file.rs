@@ -0,0 +1,380 @@
+use super::{CellKind, FieldOptions, LayoutPlacement, MineField};
+use crate::DynResult;
+use std::{collections::HashSet, fs, path::Path};
+
+// a single-layer mine layout shared with other tools, either in the binary
+// Minesweeper Board Format (MBF) or as an ASCII grid of '*' and '.'
+pub struct BoardFile {
+    pub cols: usize,
+    pub rows: usize,
+    pub mines: Vec<(usize, usize)>,
+}
+
+impl BoardFile {
+    // boards the game can play
+    const SIZES: std::ops::RangeInclusive<usize> = 9..=30;
+
+    // .mbf files are read as MBF, anything else as an ASCII grid
+    pub fn load(path: impl AsRef<Path>) -> DynResult<Self> {
+        let path = path.as_ref();
+
+        if Self::is_mbf(path) {
+            Ok(Self::from_mbf(&fs::read(path)?)?)
+        } else {
+            Ok(Self::from_ascii(&fs::read_to_string(path)?)?)
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> DynResult<()> {
+        let path = path.as_ref();
+
+        if Self::is_mbf(path) {
+            fs::write(path, self.to_mbf()?)?;
+        } else {
+            fs::write(path, self.to_ascii())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_mbf(path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("mbf"))
+    }
+
+    // width and height in a byte each, the number of mines in two bytes
+    // (big-endian), then the column and row of every mine in a byte each
+    pub fn from_mbf(bytes: &[u8]) -> Result<Self, String> {
+        let [cols, rows, high, low, rest @ ..] = bytes else {
+            return Err(format!(
+                "the header takes 4 bytes, the file has {}",
+                bytes.len()
+            ));
+        };
+
+        let (cols, rows) = (*cols as usize, *rows as usize);
+        let count = u16::from_be_bytes([*high, *low]) as usize;
+
+        if cols == 0 || rows == 0 {
+            return Err(format!("the board is {cols}x{rows}"));
+        }
+
+        if rest.len() != count * 2 {
+            return Err(format!(
+                "{} mines take {} bytes after the header, the file has {}",
+                count,
+                count * 2,
+                rest.len()
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        let mut mines = Vec::with_capacity(count);
+
+        for (index, pair) in rest.chunks_exact(2).enumerate() {
+            let (x, y) = (pair[0] as usize, pair[1] as usize);
+            let offset = 4 + index * 2;
+
+            if x >= cols || y >= rows {
+                return Err(format!(
+                    "byte {offset}: mine {} at ({x},{y}) lies outside the {cols}x{rows} board",
+                    index + 1
+                ));
+            }
+
+            if !seen.insert((x, y)) {
+                return Err(format!(
+                    "byte {offset}: mine {} at ({x},{y}) is there already",
+                    index + 1
+                ));
+            }
+
+            mines.push((x, y));
+        }
+
+        Ok(Self { cols, rows, mines })
+    }
+
+    pub fn to_mbf(&self) -> Result<Vec<u8>, String> {
+        if self.cols > 255 || self.rows > 255 {
+            return Err(format!(
+                "MBF boards are at most 255x255, not {}x{}",
+                self.cols, self.rows
+            ));
+        }
+
+        let count = u16::try_from(self.mines.len()).map_err(|_| {
+            format!(
+                "MBF boards hold at most 65535 mines, not {}",
+                self.mines.len()
+            )
+        })?;
+
+        let mut bytes = vec![self.cols as u8, self.rows as u8];
+        bytes.extend(count.to_be_bytes());
+
+        for &(x, y) in &self.mines {
+            bytes.extend([x as u8, y as u8]);
+        }
+
+        Ok(bytes)
+    }
+
+    // one line per row, all of the same length; blank lines at the end are ignored
+    pub fn from_ascii(text: &str) -> Result<Self, String> {
+        let lines: Vec<_> = text.trim_end().lines().map(str::trim_end).collect();
+
+        let Some(cols) = lines.first().map(|line| line.chars().count()) else {
+            return Err(String::from("the file has no rows"));
+        };
+
+        if cols == 0 {
+            return Err(String::from("line 1: the row is empty"));
+        }
+
+        let mut mines = Vec::new();
+
+        for (y, line) in lines.iter().enumerate() {
+            let error = |message: String| format!("line {}: {}", y + 1, message);
+            let len = line.chars().count();
+
+            if len != cols {
+                return Err(error(format!("{len} cells, the first line has {cols}")));
+            }
+
+            for (x, cell) in line.chars().enumerate() {
+                match cell {
+                    '*' => mines.push((x, y)),
+                    '.' => (),
+                    _ => {
+                        return Err(error(format!(
+                            "column {}: '{}' is neither '*' nor '.'",
+                            x + 1,
+                            cell
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            cols,
+            rows: lines.len(),
+            mines,
+        })
+    }
+
+    pub fn to_ascii(&self) -> String {
+        let mines: HashSet<_> = self.mines.iter().copied().collect();
+
+        (0..self.rows)
+            .map(|y| {
+                let mut row: String = (0..self.cols)
+                    .map(|x| if mines.contains(&(x, y)) { '*' } else { '.' })
+                    .collect();
+
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+
+    // the options of a game on this board, the rest taken from `base`
+    pub fn options(&self, base: FieldOptions) -> Result<FieldOptions, String> {
+        if !Self::SIZES.contains(&self.cols) || !Self::SIZES.contains(&self.rows) {
+            return Err(format!(
+                "the board is {}x{}, games have 9 to 30 columns and rows",
+                self.cols, self.rows
+            ));
+        }
+
+        let options = FieldOptions {
+            cols: self.cols,
+            rows: self.rows,
+            layers: 1,
+            mines: self.mines.len(),
+            mines_per_cell: 1,
+            ..base
+        };
+
+        // the first click needs its free area like on a random board
+        let max_mines = MineField::new(options).max_mines();
+
+        if self.mines.len() > max_mines {
+            return Err(format!(
+                "the board has {} mines, a {}x{} game fits at most {}",
+                self.mines.len(),
+                self.cols,
+                self.rows,
+                max_mines
+            ));
+        }
+
+        Ok(options)
+    }
+
+    // places the mines of the board instead of random ones
+    pub fn placement(&self) -> LayoutPlacement {
+        LayoutPlacement::from_mines(self.mines.clone())
+    }
+}
+
+impl MineField {
+    pub fn from_board_file(board: &BoardFile, base: FieldOptions) -> Result<Self, String> {
+        let mut field = Self::new(board.options(base)?);
+
+        field.set_mines(&board.mines);
+        field.options.mines = field.count_mines();
+
+        Ok(field)
+    }
+
+    // the current layout of the first layer, one entry per mined cell
+    pub fn to_board_file(&self) -> BoardFile {
+        let FieldOptions { cols, rows, .. } = self.options;
+
+        let mines = (0..rows)
+            .flat_map(|y| (0..cols).map(move |x| (x, y)))
+            .filter(|&(x, y)| matches!(self.cells[x][y].kind, CellKind::Mined(_)))
+            .collect();
+
+        BoardFile { cols, rows, mines }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FirstClick;
+    use super::*;
+
+    fn board() -> BoardFile {
+        BoardFile {
+            cols: 12,
+            rows: 10,
+            mines: vec![(0, 0), (11, 0), (5, 4), (0, 9), (11, 9), (6, 4)],
+        }
+    }
+
+    fn sorted(mut mines: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        mines.sort();
+        mines
+    }
+
+    fn assert_same(a: &BoardFile, b: &BoardFile) {
+        assert_eq!((a.cols, a.rows), (b.cols, b.rows));
+        assert_eq!(sorted(a.mines.clone()), sorted(b.mines.clone()));
+    }
+
+    #[test]
+    fn mbf_round_trips() {
+        let bytes = board().to_mbf().unwrap_or_default();
+
+        assert_eq!(&bytes[..4], &[12, 10, 0, 6]);
+
+        match BoardFile::from_mbf(&bytes) {
+            Ok(read) => assert_same(&board(), &read),
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    #[test]
+    fn ascii_round_trips() {
+        let text = board().to_ascii();
+
+        assert_eq!(text.lines().next(), Some("*..........*"));
+
+        match BoardFile::from_ascii(&text) {
+            Ok(read) => assert_same(&board(), &read),
+            Err(e) => panic!("{e}"),
+        }
+
+        // trailing blanks and blank lines are ignored
+        let padded = text.replace('\n', "  \n") + "\n\n";
+        assert!(BoardFile::from_ascii(&padded).is_ok_and(|read| read.rows == 10));
+    }
+
+    #[test]
+    fn field_round_trips() {
+        let Ok(field) = MineField::from_board_file(&board(), FieldOptions::default()) else {
+            panic!("the board doesn't fit a field");
+        };
+
+        assert_eq!(field.options().mines, 6);
+        assert_same(&board(), &field.to_board_file());
+    }
+
+    #[test]
+    fn short_headers_fail() {
+        assert!(BoardFile::from_mbf(&[]).is_err());
+        assert!(BoardFile::from_mbf(&[9, 9, 0]).is_err());
+        assert!(BoardFile::from_mbf(&[0, 9, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn wrong_payload_lengths_fail() {
+        let bytes = board().to_mbf().unwrap_or_default();
+
+        assert!(BoardFile::from_mbf(&bytes[..bytes.len() - 1]).is_err());
+        assert!(BoardFile::from_mbf(&bytes[..bytes.len() - 2]).is_err());
+        assert!(BoardFile::from_mbf(&[bytes.as_slice(), &[0, 1]].concat()).is_err());
+    }
+
+    #[test]
+    fn misplaced_mines_fail() {
+        let outside = [9, 9, 0, 2, 1, 1, 9, 0];
+        let twice = [9, 9, 0, 2, 4, 5, 4, 5];
+
+        assert!(BoardFile::from_mbf(&outside).is_err_and(|e| e.contains("byte 6")));
+        assert!(BoardFile::from_mbf(&twice).is_err_and(|e| e.contains("byte 6")));
+    }
+
+    #[test]
+    fn bad_grids_fail() {
+        assert!(BoardFile::from_ascii("").is_err());
+        assert!(BoardFile::from_ascii("\n\n").is_err());
+        assert!(BoardFile::from_ascii("*..\n..\n...\n").is_err_and(|e| e.starts_with("line 2")));
+        assert!(BoardFile::from_ascii("*..\n.x.\n").is_err_and(|e| e.contains("column 2")));
+    }
+
+    #[test]
+    fn board_sizes_are_checked() {
+        let small = BoardFile { cols: 8, ..board() };
+
+        assert!(small.options(FieldOptions::default()).is_err());
+        assert!(board().options(FieldOptions::default()).is_ok());
+
+        let huge = BoardFile {
+            cols: 300,
+            ..board()
+        };
+
+        assert!(huge.to_mbf().is_err());
+    }
+
+    #[test]
+    fn mine_counts_are_checked() {
+        let full = BoardFile::from_ascii(&"*********\n".repeat(9));
+
+        assert!(full.is_ok_and(|full| full
+            .options(FieldOptions::default())
+            .is_err_and(|e| e.contains("81 mines"))));
+
+        // one cell left free for the first click
+        let mut mines: Vec<_> = (0..9).flat_map(|x| (0..9).map(move |y| (x, y))).collect();
+        mines.retain(|&coords| coords != (4, 4));
+
+        let crowded = BoardFile {
+            cols: 9,
+            rows: 9,
+            mines,
+        };
+
+        assert!(crowded.options(FieldOptions::default()).is_ok());
+        assert!(crowded
+            .options(FieldOptions {
+                first_click: FirstClick::Opening,
+                ..Default::default()
+            })
+            .is_err());
+    }
+}
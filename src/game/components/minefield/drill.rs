@@ -0,0 +1,234 @@
+use super::{
+    solver::{Knowledge, Solver},
+    CellKind, CellState, FieldOptions, FirstClick, MineField, UniformPlacement,
+};
+use rand::Rng;
+use std::collections::HashMap;
+
+// runs of numbers along a wall of hidden cells that experts read at a glance
+#[derive(Clone, Copy, PartialEq)]
+pub enum Pattern {
+    OneOne,
+    OneTwo,
+    OneTwoOne,
+    OneTwoTwoOne,
+    // a 1-1 starting at the edge of the board
+    Corner,
+}
+
+// a small partially revealed position holding the pattern
+pub struct Drill {
+    pub field: MineField,
+    // every hidden cell the numbers prove, and whether it is mined
+    pub proved: HashMap<(usize, usize), bool>,
+    // proved cells along the wall of the pattern, all of them have to be answered
+    pub targets: Vec<(usize, usize)>,
+}
+
+impl Pattern {
+    pub const ALL: [Self; 5] = [
+        Self::OneOne,
+        Self::OneTwo,
+        Self::OneTwoOne,
+        Self::OneTwoTwoOne,
+        Self::Corner,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|pattern| pattern.name() == name)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::OneOne => "1-1",
+            Self::OneTwo => "1-2",
+            Self::OneTwoOne => "1-2-1",
+            Self::OneTwoTwoOne => "1-2-2-1",
+            Self::Corner => "corner",
+        }
+    }
+
+    fn numbers(self) -> &'static [u16] {
+        match self {
+            Self::OneOne | Self::Corner => &[1, 1],
+            Self::OneTwo => &[1, 2],
+            Self::OneTwoOne => &[1, 2, 1],
+            Self::OneTwoTwoOne => &[1, 2, 2, 1],
+        }
+    }
+}
+
+impl Drill {
+    const SIZE: usize = 9;
+    // positions tried before giving up on a pattern; the rarest one shows up
+    // about once in 80 tries, so a miss is unlikely and the window never waits long
+    const ATTEMPTS: usize = 1_000;
+
+    // plays random boards from their first click, opening a few proved cells more,
+    // until the pattern shows up with something to prove next to it
+    pub fn generate(pattern: Pattern) -> Option<Self> {
+        let mut rng = rand::rng();
+
+        for _ in 0..Self::ATTEMPTS {
+            let options = FieldOptions {
+                cols: Self::SIZE,
+                rows: Self::SIZE,
+                layers: 1,
+                mines: rng.random_range(10..=16),
+                mines_per_cell: 1,
+                first_click: FirstClick::Opening,
+                ..Default::default()
+            };
+
+            let mut field = MineField::new(options);
+            let (x, y) = (
+                rng.random_range(0..Self::SIZE),
+                rng.random_range(0..Self::SIZE),
+            );
+
+            field.place_mines(x, y, &mut UniformPlacement);
+            field.reveal_from(x, y);
+
+            for _ in 0..rng.random_range(0..3) {
+                for deduction in Solver::new(&field).deduce() {
+                    if deduction.knowledge == Knowledge::Safe {
+                        let (x, y) = deduction.coords;
+                        field.reveal_from(x, y);
+                    }
+                }
+            }
+
+            let mut solver = Solver::new(&field);
+            solver.solve();
+
+            let proved: HashMap<_, _> = field
+                .get_coords_all()
+                .filter(|&(x, y)| field.cells[x][y].state != CellState::Revealed)
+                .filter_map(|(x, y)| match solver.knowledge(x, y) {
+                    Knowledge::Unknown => None,
+                    knowledge => Some(((x, y), knowledge == Knowledge::Mined)),
+                })
+                .collect();
+
+            if let Some(targets) = field.find_pattern(pattern, &proved) {
+                return Some(Self {
+                    field,
+                    proved,
+                    targets,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+impl MineField {
+    // looks for the numbers of the pattern in a straight line with hidden cells
+    // all along one side and revealed ones along the other
+    fn find_pattern(
+        &self,
+        pattern: Pattern,
+        proved: &HashMap<(usize, usize), bool>,
+    ) -> Option<Vec<(usize, usize)>> {
+        let numbers = pattern.numbers();
+        let len = numbers.len() as isize;
+
+        // along the run, then towards the wall
+        let directions = [
+            ((1, 0), (0, -1)),
+            ((1, 0), (0, 1)),
+            ((0, 1), (-1, 0)),
+            ((0, 1), (1, 0)),
+        ];
+
+        let get = |x: isize, y: isize| {
+            let options = self.options;
+            let on_board =
+                x >= 0 && y >= 0 && (x as usize) < options.cols && (y as usize) < options.rows;
+
+            on_board.then(|| self.cells[x as usize][y as usize])
+        };
+
+        for (x, y) in self.get_coords_all() {
+            for ((dx, dy), (wx, wy)) in directions {
+                let (x, y) = (x as isize, y as isize);
+                let at = |i: isize| (x + dx * i, y + dy * i);
+
+                let matches = numbers.iter().enumerate().all(|(i, &number)| {
+                    let (x, y) = at(i as isize);
+                    get(x, y).is_some_and(|cell| {
+                        cell.state == CellState::Revealed && cell.kind == CellKind::Num(number)
+                    })
+                });
+
+                if !matches {
+                    continue;
+                }
+
+                // the run has to end where the pattern ends, at a revealed cell or the edge
+                let (bx, by) = at(-1);
+                let (ex, ey) = at(len);
+                let bounded = |x, y| get(x, y).is_none_or(|cell| cell.state == CellState::Revealed);
+
+                if !bounded(bx, by) || !bounded(ex, ey) {
+                    continue;
+                }
+
+                if (pattern == Pattern::Corner) != get(bx, by).is_none() {
+                    continue;
+                }
+
+                let sides_fit = (-1..=len).all(|i| {
+                    let (x, y) = at(i);
+                    let wall =
+                        get(x + wx, y + wy).is_none_or(|cell| cell.state != CellState::Revealed);
+                    let open =
+                        get(x - wx, y - wy).is_none_or(|cell| cell.state == CellState::Revealed);
+
+                    wall && open
+                });
+
+                if !sides_fit {
+                    continue;
+                }
+
+                let targets: Vec<_> = (-1..=len)
+                    .map(at)
+                    .filter(|&(x, y)| get(x + wx, y + wy).is_some())
+                    .map(|(x, y)| ((x + wx) as usize, (y + wy) as usize))
+                    .filter(|coords| proved.contains_key(coords))
+                    .collect();
+
+                if !targets.is_empty() {
+                    return Some(targets);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drills_show_proved_targets() {
+        for pattern in Pattern::ALL {
+            let Some(drill) = Drill::generate(pattern) else {
+                panic!("no position for {}", pattern.name());
+            };
+
+            assert!(!drill.targets.is_empty());
+
+            for target in &drill.targets {
+                let (x, y) = *target;
+
+                assert!(drill.proved.contains_key(target));
+                assert!(drill.field.cells[x][y].state != CellState::Revealed);
+            }
+        }
+    }
+}
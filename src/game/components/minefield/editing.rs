@@ -0,0 +1,147 @@
+use super::{CellKind, FieldOptions, LayoutPlacement, MineField, MinePlacement};
+use rand::seq::SliceRandom;
+
+// boards put together by hand; the mine count of the options
+// follows every change, below the usual minimum of 10 too
+impl MineField {
+    // a board in the format read by LayoutPlacement, mines beyond the field are dropped
+    pub fn from_layout(options: FieldOptions, text: &str) -> Result<Self, String> {
+        let mut field = Self::new(options);
+        let free: Vec<_> = field.get_coords_all().collect();
+        let mines = LayoutPlacement::from_text(text)?.place(&field, &free);
+
+        field.set_mines(&mines);
+        field.options.mines = field.count_mines();
+
+        Ok(field)
+    }
+
+    // adds a mine to the cell, a full one is emptied instead
+    pub fn cycle_mine(&mut self, x: usize, y: usize) {
+        match self.cells[x][y].kind {
+            CellKind::Mined(n) if n >= self.options.mines_per_cell => self.remove_mine(x, y),
+            _ => {
+                self.set_mines(&[(x, y)]);
+                self.options.mines = self.count_mines();
+            }
+        }
+    }
+
+    // takes every mine out of the cell
+    pub fn remove_mine(&mut self, x: usize, y: usize) {
+        let mines: Vec<_> = self
+            .get_mine_coords()
+            .into_iter()
+            .filter(|&coords| coords != (x, y))
+            .collect();
+
+        self.replace_mines(&mines);
+    }
+
+    pub fn remove_all_mines(&mut self) {
+        self.replace_mines(&[]);
+    }
+
+    // adds mines at random until there are as many as asked
+    pub fn fill_mines(&mut self, count: usize) {
+        let per_cell = self.options.mines_per_cell;
+
+        let mut slots: Vec<_> = self
+            .get_coords_all()
+            .flat_map(|(x, y)| {
+                let placed = match self.cells[x][y].kind {
+                    CellKind::Mined(n) => n,
+                    CellKind::Num(_) => 0,
+                };
+
+                std::iter::repeat_n((x, y), (per_cell - placed) as usize)
+            })
+            .collect();
+
+        slots.shuffle(&mut rand::rng());
+        slots.truncate(count.saturating_sub(self.count_mines()));
+
+        self.set_mines(&slots);
+        self.options.mines = self.count_mines();
+    }
+
+    // flips every layer left to right
+    pub fn mirror(&mut self) {
+        let cols = self.options.cols;
+
+        let mines: Vec<_> = self
+            .get_mine_coords()
+            .into_iter()
+            .map(|(x, y)| (x / cols * cols + cols - 1 - x % cols, y))
+            .collect();
+
+        self.replace_mines(&mines);
+    }
+
+    // a board of another size keeping the mines that still fit,
+    // the size is clamped the way new boards are
+    pub fn resized(&self, cols: usize, rows: usize) -> Self {
+        let old_cols = self.options.cols;
+
+        let mut field = Self::new(FieldOptions {
+            cols,
+            rows,
+            ..self.options
+        });
+
+        let new_cols = field.options.cols;
+
+        let mines: Vec<_> = self
+            .get_mine_coords()
+            .into_iter()
+            .filter(|&(x, _)| x % old_cols < new_cols)
+            .map(|(x, y)| (x / old_cols * new_cols + x % old_cols, y))
+            .collect();
+
+        field.set_mines(&mines);
+        field.options.mines = field.count_mines();
+
+        field
+    }
+
+    // one line per row across all layers: '*' for a mine, a digit
+    // for a cell holding several of them and '.' for the rest
+    pub fn to_layout(&self) -> String {
+        let options = self.options;
+
+        (0..options.rows)
+            .map(|y| {
+                let mut row: String = (0..options.total_cols())
+                    .map(|x| match self.cells[x][y].kind {
+                        CellKind::Mined(1) => '*',
+                        CellKind::Mined(n) => (b'0' + n) as char,
+                        CellKind::Num(_) => '.',
+                    })
+                    .collect();
+
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+
+    // every mine once, a cell with several of them repeated
+    fn get_mine_coords(&self) -> Vec<(usize, usize)> {
+        self.get_coords_all()
+            .flat_map(|(x, y)| {
+                let count = match self.cells[x][y].kind {
+                    CellKind::Mined(n) => n as usize,
+                    CellKind::Num(_) => 0,
+                };
+
+                std::iter::repeat_n((x, y), count)
+            })
+            .collect()
+    }
+
+    fn replace_mines(&mut self, mines: &[(usize, usize)]) {
+        self.clear_mines();
+        self.set_mines(mines);
+        self.options.mines = self.count_mines();
+    }
+}
@@ -0,0 +1,132 @@
+use super::{
+    solver::{Deduction, Knowledge, Rule, Solver},
+    MineField,
+};
+
+// the next thing that can be proved on the board and why
+pub struct Hint {
+    pub x: usize,
+    pub y: usize,
+    pub is_safe: bool,
+    pub text: String,
+}
+
+impl MineField {
+    // looks at the revealed cells only, safe cells are preferred
+    pub fn get_hint(&self) -> Option<Hint> {
+        let deductions = Solver::new(self).deduce();
+
+        let deduction = deductions
+            .iter()
+            .find(|deduction| deduction.knowledge == Knowledge::Safe)
+            .or(deductions.first())?;
+
+        let (x, y) = deduction.coords;
+
+        Some(Hint {
+            x,
+            y,
+            is_safe: deduction.knowledge == Knowledge::Safe,
+            text: self.explain(deduction),
+        })
+    }
+
+    // every step of solving the board from its first click, one line per proved cell;
+    // guesses are reported when the solver gets stuck
+    pub fn explain_solution(&self, init_x: usize, init_y: usize) -> Vec<String> {
+        let mut lines = vec![format!("open {}", self.format_coords((init_x, init_y)))];
+
+        let mut solver = Solver::unrevealed(self);
+        solver.reveal(init_x, init_y);
+
+        loop {
+            let deductions = solver.deduce();
+
+            if !deductions.is_empty() {
+                lines.extend(deductions.iter().map(|deduction| self.explain(deduction)));
+                solver.apply(&deductions);
+                continue;
+            }
+
+            let guess = self.get_coords_all().find(|&(x, y)| {
+                let cell = unsafe { self.get_cell_unchecked(x, y) };
+                !cell.is_mined() && solver.knowledge(x, y) == Knowledge::Unknown
+            });
+
+            let Some((x, y)) = guess else {
+                break;
+            };
+
+            lines.push(format!(
+                "nothing can be proved, {} has to be guessed",
+                self.format_coords((x, y))
+            ));
+            solver.reveal(x, y);
+        }
+
+        lines
+    }
+
+    fn explain(&self, deduction: &Deduction) -> String {
+        let cell = self.format_coords(deduction.coords);
+
+        let verdict = match deduction.knowledge {
+            Knowledge::Mined => "is a mine",
+            _ => "is safe",
+        };
+
+        match (deduction.rule, &deduction.sources[..]) {
+            (Rule::Single, &[source]) => {
+                let number = self.describe_number(source);
+
+                match deduction.knowledge {
+                    Knowledge::Mined => format!(
+                        "the {number} has as many unknown cells around as mines left, so {cell} {verdict}"
+                    ),
+                    _ => format!("the {number} already has all of its mines, so {cell} {verdict}"),
+                }
+            }
+            (Rule::Subset, &[smaller, larger]) if self.are_adjacent(smaller, larger) => {
+                let (a, b) = (self.get_number(smaller), self.get_number(larger));
+                let (from, to) = (self.format_coords(smaller), self.format_coords(larger));
+
+                format!("the {a}-{b} pattern at {from}-{to} proves {cell} {verdict}")
+            }
+            (Rule::Subset, &[smaller, larger]) => format!(
+                "subset rule: the {} minus the {} proves {cell} {verdict}",
+                self.describe_number(larger),
+                self.describe_number(smaller)
+            ),
+            _ => format!("{cell} {verdict}"),
+        }
+    }
+
+    fn describe_number(&self, coords: (usize, usize)) -> String {
+        format!(
+            "{} at {}",
+            self.get_number(coords),
+            self.format_coords(coords)
+        )
+    }
+
+    fn get_number(&self, (x, y): (usize, usize)) -> u16 {
+        unsafe { self.get_cell_unchecked(x, y) }.kind().to_int()
+    }
+
+    // numbers next to each other in a row or a column
+    fn are_adjacent(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == 1
+            && a.0 / self.options.cols == b.0 / self.options.cols
+    }
+
+    // the layer is added on stacked boards
+    pub(super) fn format_coords(&self, (x, y): (usize, usize)) -> String {
+        let cols = self.options.cols;
+
+        if self.options.layers > 1 {
+            format!("({},{},{})", x % cols, y, x / cols)
+        } else {
+            format!("({x},{y})")
+        }
+    }
+}
@@ -0,0 +1,422 @@
+use super::{
+    big_count::BigCount,
+    probability::{add_ln, Frontier},
+    MineField,
+};
+use rand::Rng;
+use std::iter;
+
+// a layout lists the coordinates of every hidden mine,
+// a cell holding several of them appears once for each
+
+impl MineField {
+    // the layouts agreeing with the revealed numbers and the total number of mines,
+    // flags are not trusted; None when a revealed cell is mined or the board is too
+    // open to count, numbers contradicting each other give zero
+    pub fn count_layouts(&self) -> Option<BigCount> {
+        let frontier = self.get_frontier_groups()?;
+
+        let count = self
+            .weigh_layouts(&frontier)
+            .iter()
+            .fold(BigCount::zero(), |sum, weight| &sum + weight);
+
+        Some(count)
+    }
+
+    // the first `limit` layouts, in no particular order
+    pub fn enumerate_layouts(&self, limit: usize) -> Option<Vec<Vec<(usize, usize)>>> {
+        let frontier = self.get_frontier_groups()?;
+        let mut layouts = Vec::new();
+
+        if limit > 0 {
+            self.enumerate_groups(&frontier, 0, &mut Vec::new(), &mut layouts, limit);
+        }
+
+        Some(layouts)
+    }
+
+    // one layout drawn with the same chance for each; the mines of the frontier are
+    // chosen exactly, the interior ones cell by cell, weighted by the ways left for
+    // the cells after it; None when there are no layouts to draw from
+    pub fn sample_layout(&self, rng: &mut impl Rng) -> Option<Vec<(usize, usize)>> {
+        let frontier = self.get_frontier_groups()?;
+        let per_cell = self.options.mines_per_cell as usize;
+
+        let mut placed = pick(&self.weigh_layouts(&frontier), rng)?;
+        let interior_mines = self.count_mines() - placed;
+
+        let counts = get_exact_counts(&frontier);
+        let mut mines = Vec::new();
+
+        // how many mines each group takes, then which of its solutions
+        for (i, group) in frontier.groups.iter().enumerate() {
+            let others = convolve(&counts[i + 1..]);
+
+            let weights: Vec<_> = counts[i]
+                .iter()
+                .enumerate()
+                .map(
+                    |(k, count)| match placed.checked_sub(k).and_then(|rest| others.get(rest)) {
+                        Some(other) => count * other,
+                        None => BigCount::zero(),
+                    },
+                )
+                .collect();
+
+            let taken = pick(&weights, rng)?;
+            let mut skipped = rng.random_range(0..group.counts[taken] as u64);
+            placed -= taken;
+
+            group.walk(per_cell, &mut |values| {
+                if values.iter().sum::<usize>() != taken {
+                    return true;
+                }
+
+                if skipped > 0 {
+                    skipped -= 1;
+                    return true;
+                }
+
+                for (index, &value) in values.iter().enumerate() {
+                    let coords = frontier.cells[group.cells[index]];
+                    mines.extend(iter::repeat_n(coords, value));
+                }
+
+                false
+            });
+        }
+
+        let cells = &frontier.interior;
+        let ln_ways = get_spread_table(cells.len(), per_cell, interior_mines);
+        let mut left = interior_mines;
+
+        for (i, &coords) in cells.iter().enumerate() {
+            let after = &ln_ways[cells.len() - i - 1];
+
+            // scaled by the largest term so the weights stay in range
+            let lns: Vec<_> = (0..=per_cell.min(left))
+                .map(|value| after[left - value])
+                .collect();
+            let scale = lns.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let weights: Vec<_> = lns.iter().map(|ln| (ln - scale).exp()).collect();
+
+            let total = weights.iter().sum::<f64>();
+            let mut roll = rng.random_range(0.0..1.0) * total;
+            let mut value = 0;
+
+            while value + 1 < weights.len() && roll >= weights[value] {
+                roll -= weights[value];
+                value += 1;
+            }
+
+            mines.extend(iter::repeat_n(coords, value));
+            left -= value;
+        }
+
+        Some(mines)
+    }
+}
+
+// private methods
+impl MineField {
+    // layouts by the number of mines on the frontier
+    fn weigh_layouts(&self, frontier: &Frontier) -> Vec<BigCount> {
+        let per_cell = self.options.mines_per_cell as usize;
+        let total = self.count_mines();
+        let all = convolve(&get_exact_counts(frontier));
+
+        all.iter()
+            .enumerate()
+            .map(|(k, count)| match total.checked_sub(k) {
+                Some(rest) if !count.is_zero() => {
+                    count * &count_spreads(frontier.interior.len(), per_cell, rest)
+                }
+                _ => BigCount::zero(),
+            })
+            .collect()
+    }
+
+    // returns false once the limit is reached
+    fn enumerate_groups(
+        &self,
+        frontier: &Frontier,
+        index: usize,
+        mines: &mut Vec<(usize, usize)>,
+        layouts: &mut Vec<Vec<(usize, usize)>>,
+        limit: usize,
+    ) -> bool {
+        let per_cell = self.options.mines_per_cell as usize;
+
+        let Some(group) = frontier.groups.get(index) else {
+            let Some(left) = self.count_mines().checked_sub(mines.len()) else {
+                return true;
+            };
+
+            return enumerate_spreads(&frontier.interior, left, per_cell, mines, layouts, limit);
+        };
+
+        group.walk(per_cell, &mut |values| {
+            let before = mines.len();
+
+            for (i, &value) in values.iter().enumerate() {
+                let coords = frontier.cells[group.cells[i]];
+                mines.extend(iter::repeat_n(coords, value));
+            }
+
+            let more = self.enumerate_groups(frontier, index + 1, mines, layouts, limit);
+            mines.truncate(before);
+
+            more
+        })
+    }
+}
+
+// every way of spreading `left` mines over the cells after the mines given
+fn enumerate_spreads(
+    cells: &[(usize, usize)],
+    left: usize,
+    per_cell: usize,
+    mines: &mut Vec<(usize, usize)>,
+    layouts: &mut Vec<Vec<(usize, usize)>>,
+    limit: usize,
+) -> bool {
+    if left > cells.len() * per_cell {
+        return true;
+    }
+
+    let Some((&coords, rest)) = cells.split_first() else {
+        layouts.push(mines.clone());
+        return layouts.len() < limit;
+    };
+
+    for value in 0..=per_cell.min(left) {
+        mines.extend(iter::repeat_n(coords, value));
+        let more = enumerate_spreads(rest, left - value, per_cell, mines, layouts, limit);
+        mines.truncate(mines.len() - value);
+
+        if !more {
+            return false;
+        }
+    }
+
+    true
+}
+
+// the solutions of every group by their number of mines; the searches
+// count one at a time, so far below where f64 stops being exact
+fn get_exact_counts(frontier: &Frontier) -> Vec<Vec<BigCount>> {
+    frontier
+        .groups
+        .iter()
+        .map(|group| {
+            group
+                .counts
+                .iter()
+                .map(|&count| BigCount::from(count as u64))
+                .collect()
+        })
+        .collect()
+}
+
+fn convolve(groups: &[Vec<BigCount>]) -> Vec<BigCount> {
+    groups.iter().fold(vec![BigCount::one()], |all, counts| {
+        let mut result = vec![BigCount::zero(); all.len() + counts.len() - 1];
+
+        for (i, a) in all.iter().enumerate() {
+            for (j, b) in counts.iter().enumerate() {
+                result[i + j] = &result[i + j] + &(a * b);
+            }
+        }
+
+        result
+    })
+}
+
+// ways of spreading the mines over the cells, each holding up to `per_cell`
+// of them, by inclusion-exclusion over the cells holding too many
+fn count_spreads(cells: usize, per_cell: usize, mines: usize) -> BigCount {
+    if cells == 0 {
+        return if mines == 0 {
+            BigCount::one()
+        } else {
+            BigCount::zero()
+        };
+    }
+
+    if per_cell == 1 {
+        return BigCount::binomial(cells, mines);
+    }
+
+    let mut added = BigCount::zero();
+    let mut taken = BigCount::zero();
+
+    for full in 0..=cells.min(mines / (per_cell + 1)) {
+        let rest = mines - full * (per_cell + 1);
+        let term =
+            &BigCount::binomial(cells, full) * &BigCount::binomial(rest + cells - 1, cells - 1);
+
+        if full % 2 == 0 {
+            added = &added + &term;
+        } else {
+            taken = &taken + &term;
+        }
+    }
+
+    &added - &taken
+}
+
+// logarithms of the ways of spreading 0..=max mines over j cells, for every j
+fn get_spread_table(cells: usize, per_cell: usize, max: usize) -> Vec<Vec<f64>> {
+    let mut first = vec![f64::NEG_INFINITY; max + 1];
+    first[0] = 0.0;
+
+    let mut table = vec![first];
+
+    for j in 0..cells {
+        let previous = &table[j];
+
+        let row = (0..=max)
+            .map(|mines| {
+                (0..=per_cell.min(mines))
+                    .map(|value| previous[mines - value])
+                    .fold(f64::NEG_INFINITY, add_ln)
+            })
+            .collect();
+
+        table.push(row);
+    }
+
+    table
+}
+
+// an index drawn with a chance proportional to its weight
+fn pick(weights: &[BigCount], rng: &mut impl Rng) -> Option<usize> {
+    let total = weights
+        .iter()
+        .fold(BigCount::zero(), |sum, weight| &sum + weight);
+
+    let mut roll = total.random_below(rng);
+
+    for (i, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            return Some(i);
+        }
+
+        roll = &roll - weight;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        tests::{brute_force_layouts, field_with_hidden},
+        CellKind,
+    };
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
+
+    // every hidden cell touches a revealed number
+    fn frontier_only() -> MineField {
+        let mines = [(4, 1), (4, 2), (1, 4), (6, 4), (4, 7)];
+        field_with_hidden(1, &mines, |x, y| x == 4 || y == 4)
+    }
+
+    // a corner block whose inner cells see no number
+    fn with_interior() -> MineField {
+        let mines = [(0, 0), (1, 2), (3, 1), (2, 3), (0, 3)];
+        field_with_hidden(1, &mines, |x, y| x < 4 && y < 4)
+    }
+
+    fn multimines() -> MineField {
+        let mines = [(0, 0), (0, 0), (2, 1), (1, 2)];
+        field_with_hidden(2, &mines, |x, y| x < 3 && y < 3)
+    }
+
+    fn sorted(mut layout: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        layout.sort();
+        layout
+    }
+
+    #[test]
+    fn counts_match_brute_force() {
+        for field in [frontier_only(), with_interior(), multimines()] {
+            let layouts = brute_force_layouts(&field);
+            assert!(!layouts.is_empty());
+
+            let count = field.count_layouts().map(|count| count.to_string());
+            assert_eq!(count, Some(layouts.len().to_string()));
+        }
+    }
+
+    #[test]
+    fn enumeration_matches_brute_force() {
+        for field in [frontier_only(), with_interior(), multimines()] {
+            let expected: HashSet<_> = brute_force_layouts(&field).into_iter().collect();
+
+            let found = field.enumerate_layouts(usize::MAX).unwrap_or_default();
+            let unique: HashSet<_> = found.iter().cloned().map(sorted).collect();
+
+            assert_eq!(found.len(), unique.len());
+            assert_eq!(unique, expected);
+
+            let limited = field.enumerate_layouts(3).unwrap_or_default();
+            assert_eq!(limited.len(), expected.len().min(3));
+        }
+    }
+
+    #[test]
+    fn samples_are_valid_and_spread() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for field in [frontier_only(), with_interior(), multimines()] {
+            let layouts = brute_force_layouts(&field);
+            let expected: HashSet<_> = layouts.iter().cloned().collect();
+            let mut seen = HashSet::new();
+
+            for _ in 0..20 * layouts.len() {
+                let Some(layout) = field.sample_layout(&mut rng) else {
+                    panic!("no layout drawn");
+                };
+
+                let layout = sorted(layout);
+                assert!(expected.contains(&layout));
+                seen.insert(layout);
+            }
+
+            // with 20 draws per layout, missing one is far from likely
+            assert_eq!(seen.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn open_board_counts_binomials() {
+        let mines: Vec<_> = (0..9).map(|x| (x, x * 4 % 9)).collect();
+        let field = field_with_hidden(1, &mines, |_, _| true);
+
+        let count = field.count_layouts().map(|count| count.to_string());
+        assert_eq!(count, Some(BigCount::binomial(81, 9).to_string()));
+
+        let mut rng = StdRng::seed_from_u64(5);
+
+        for _ in 0..20 {
+            let layout = field.sample_layout(&mut rng).unwrap_or_default();
+            let cells: HashSet<_> = layout.iter().collect();
+
+            assert_eq!(layout.len(), 9);
+            assert_eq!(cells.len(), 9);
+        }
+    }
+
+    #[test]
+    fn contradictions_have_no_layouts() {
+        let mut field = frontier_only();
+        field.cells[3][3].kind = CellKind::Num(8);
+
+        assert!(field.count_layouts().is_some_and(|count| count.is_zero()));
+        assert!(field.sample_layout(&mut StdRng::seed_from_u64(1)).is_none());
+    }
+}
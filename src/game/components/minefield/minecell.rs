@@ -4,47 +4,48 @@ pub struct MineCell {
     pub state: CellState,
 }
 
-#[repr(u8)]
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum CellKind {
-    #[default]
-    Num0,
-    Num1,
-    Num2,
-    Num3,
-    Num4,
-    Num5,
-    Num6,
-    Num7,
-    Num8,
-    Mined,
+    // up to 146 neighbours with 3 mines each on a full custom mask across layers
+    Num(u16),
+    // number of mines in the cell
+    Mined(u8),
+}
+
+impl Default for CellKind {
+    fn default() -> Self {
+        Self::Num(0)
+    }
 }
 
 impl CellKind {
-    pub fn to_int(self) -> u8 {
-        self as u8
+    // mined cells don't carry a number
+    pub fn to_int(self) -> u16 {
+        match self {
+            Self::Num(n) => n,
+            Self::Mined(_) => u16::MAX,
+        }
     }
 
-    pub fn increment(&mut self) {
-        *self = match self {
-            Self::Num0 => Self::Num1,
-            Self::Num1 => Self::Num2,
-            Self::Num2 => Self::Num3,
-            Self::Num3 => Self::Num4,
-            Self::Num4 => Self::Num5,
-            Self::Num5 => Self::Num6,
-            Self::Num6 => Self::Num7,
-            Self::Num7 => Self::Num8,
-            Self::Num8 => Self::Num8,
-            Self::Mined => Self::Mined,
+    pub fn add_mine(&mut self) {
+        *self = match *self {
+            Self::Num(_) => Self::Mined(1),
+            Self::Mined(n) => Self::Mined(n + 1),
         };
     }
+
+    pub fn increment(&mut self) {
+        if let Self::Num(n) = self {
+            *n += 1;
+        }
+    }
 }
 
 #[derive(Default, Clone, Copy, PartialEq)]
 pub enum CellState {
     #[default]
     Hidden,
-    Flagged,
+    // number of flags put on the cell
+    Flagged(u8),
     Revealed,
 }
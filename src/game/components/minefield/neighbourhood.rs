@@ -0,0 +1,173 @@
+use super::Tiling;
+
+// 7x7 window of offsets centered on a cell, one bit per offset
+#[derive(Default, Clone, Copy, PartialEq)]
+pub struct OffsetMask(u64);
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Neighbourhood {
+    // cells sharing an edge or a vertex, depends on the tiling
+    #[default]
+    Adjacent,
+    // the ones below are used by square tiling only
+    Orthogonal,
+    Knight,
+    Radius2,
+    Custom(OffsetMask),
+}
+
+const SQUARE: OffsetMask = OffsetMask::from_offsets(&[
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+]);
+
+const HEX_EVEN: OffsetMask =
+    OffsetMask::from_offsets(&[(-1, -1), (0, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)]);
+const HEX_ODD: OffsetMask =
+    OffsetMask::from_offsets(&[(0, -1), (1, -1), (-1, 0), (1, 0), (0, 1), (1, 1)]);
+
+// triangles also touch the ones sharing a single vertex
+const TRIANGLE_UP: OffsetMask = OffsetMask::from_offsets(&[
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-2, 0),
+    (-1, 0),
+    (1, 0),
+    (2, 0),
+    (-2, 1),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (2, 1),
+]);
+
+const TRIANGLE_DOWN: OffsetMask = OffsetMask::from_offsets(&[
+    (-2, -1),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (2, -1),
+    (-2, 0),
+    (-1, 0),
+    (1, 0),
+    (2, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+]);
+
+const ORTHOGONAL: OffsetMask = OffsetMask::from_offsets(&[(0, -1), (-1, 0), (1, 0), (0, 1)]);
+
+const KNIGHT: OffsetMask = OffsetMask::from_offsets(&[
+    (-1, -2),
+    (1, -2),
+    (-2, -1),
+    (2, -1),
+    (-2, 1),
+    (2, 1),
+    (-1, 2),
+    (1, 2),
+]);
+
+impl OffsetMask {
+    pub const RADIUS: isize = 3;
+    const SIZE: isize = Self::RADIUS * 2 + 1;
+
+    pub const fn from_offsets(offsets: &[(isize, isize)]) -> Self {
+        let mut mask = Self(0);
+        let mut i = 0;
+
+        while i < offsets.len() {
+            let (dx, dy) = offsets[i];
+            mask.0 |= 1 << Self::index(dx, dy);
+            i += 1;
+        }
+
+        mask
+    }
+
+    pub fn square(radius: isize) -> Self {
+        let radius = radius.min(Self::RADIUS);
+        let mut mask = Self(0);
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                mask.insert(dx, dy);
+            }
+        }
+
+        mask
+    }
+
+    pub fn contains(self, dx: isize, dy: isize) -> bool {
+        Self::in_range(dx, dy) && self.0 >> Self::index(dx, dy) & 1 == 1
+    }
+
+    // the center is never a neighbour of itself, returns false for it
+    // and for offsets out of the window
+    pub fn insert(&mut self, dx: isize, dy: isize) -> bool {
+        if !Self::in_range(dx, dy) || (dx, dy) == (0, 0) {
+            return false;
+        }
+
+        self.0 |= 1 << Self::index(dx, dy);
+        true
+    }
+
+    // the offsets pointing the other way, so the cells that see a given cell
+    // are found even when the mask isn't symmetric
+    pub fn reflected(self) -> Self {
+        let mut mask = Self(0);
+
+        for (dx, dy) in self.offsets() {
+            mask.insert(-dx, -dy);
+        }
+
+        mask
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn offsets(self) -> impl Iterator<Item = (isize, isize)> {
+        (0..Self::SIZE * Self::SIZE)
+            .filter(move |&i| self.0 >> i & 1 == 1)
+            .map(|i| (i % Self::SIZE - Self::RADIUS, i / Self::SIZE - Self::RADIUS))
+    }
+
+    fn in_range(dx: isize, dy: isize) -> bool {
+        dx.abs() <= Self::RADIUS && dy.abs() <= Self::RADIUS
+    }
+
+    const fn index(dx: isize, dy: isize) -> isize {
+        (dy + Self::RADIUS) * Self::SIZE + dx + Self::RADIUS
+    }
+}
+
+impl Neighbourhood {
+    pub fn get_mask(self, tiling: Tiling, x: usize, y: usize) -> OffsetMask {
+        match (tiling, self) {
+            (Tiling::Square, Self::Adjacent) => SQUARE,
+            (Tiling::Square, Self::Orthogonal) => ORTHOGONAL,
+            (Tiling::Square, Self::Knight) => KNIGHT,
+            (Tiling::Square, Self::Radius2) => OffsetMask::square(2),
+            (Tiling::Square, Self::Custom(mask)) => mask,
+            (Tiling::Hex, _) if y.is_multiple_of(2) => HEX_EVEN,
+            (Tiling::Hex, _) => HEX_ODD,
+            (Tiling::Triangle, _) if (x + y).is_multiple_of(2) => TRIANGLE_UP,
+            (Tiling::Triangle, _) => TRIANGLE_DOWN,
+        }
+    }
+}
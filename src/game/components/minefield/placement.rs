@@ -0,0 +1,386 @@
+use super::{solver::Solver, MineField};
+use crate::DynResult;
+use rand::{
+    rngs::StdRng,
+    seq::{index::sample, SliceRandom},
+    Rng, SeedableRng,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+// decides where the mines go once the first cell is clicked
+pub trait MinePlacement {
+    // picks the cells to be mined out of the free ones (all usable cells but the area
+    // around the first click); a cell is picked once per mine it holds
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)>;
+}
+
+// built-in strategies that can be picked in FieldOptions and presets
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum Placement {
+    #[default]
+    Uniform,
+    // the same boards in the same order for the same seed
+    Seeded(u64),
+    // boards that can be solved from the first click without guessing
+    NoGuess,
+    Symmetric(Symmetry),
+    // mines are drawn towards the ones already placed
+    Clustered,
+    // the mines of a layout file, read once and kept for the whole run
+    Layout(&'static [(usize, usize)]),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Symmetry {
+    // left and right halves of every layer mirror each other
+    Mirror,
+    // every layer looks the same when turned upside down
+    Rotational,
+}
+
+pub struct UniformPlacement;
+
+pub struct SeededPlacement(StdRng);
+
+pub struct NoGuessPlacement;
+
+pub struct SymmetricPlacement(Symmetry);
+
+pub struct ClusteredPlacement;
+
+// a fixed layout read from a file
+pub struct LayoutPlacement(Vec<(usize, usize)>);
+
+impl Placement {
+    pub fn build(self) -> Box<dyn MinePlacement> {
+        match self {
+            Self::Uniform => Box::new(UniformPlacement),
+            Self::Seeded(seed) => Box::new(SeededPlacement::new(seed)),
+            Self::NoGuess => Box::new(NoGuessPlacement),
+            Self::Symmetric(symmetry) => Box::new(SymmetricPlacement(symmetry)),
+            Self::Clustered => Box::new(ClusteredPlacement),
+            Self::Layout(mines) => Box::new(LayoutPlacement::from_mines(mines.to_vec())),
+        }
+    }
+}
+
+impl MinePlacement for UniformPlacement {
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        place_uniform(&mut rand::rng(), field, free)
+    }
+}
+
+impl SeededPlacement {
+    pub fn new(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl MinePlacement for SeededPlacement {
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        place_uniform(&mut self.0, field, free)
+    }
+}
+
+impl NoGuessPlacement {
+    const ATTEMPTS: usize = 500;
+}
+
+// works best when the first click opens a zero (see FirstClick::Opening),
+// the last board tried is kept when none of them can be solved
+impl MinePlacement for NoGuessPlacement {
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let free_set: HashSet<_> = free.iter().copied().collect();
+
+        // the first click opens the whole area kept free around it
+        let opened: Vec<_> = field
+            .get_coords_all()
+            .filter(|coords| !free_set.contains(coords))
+            .collect();
+
+        let mut mines = Vec::new();
+
+        for _ in 0..Self::ATTEMPTS {
+            mines = place_uniform(&mut rand::rng(), field, free);
+
+            let mut scratch = MineField::new(field.options());
+            scratch.set_mines(&mines);
+
+            let mut solver = Solver::new(&scratch);
+
+            for &(x, y) in &opened {
+                solver.reveal(x, y);
+            }
+
+            if solver.solve() {
+                break;
+            }
+        }
+
+        mines
+    }
+}
+
+// mines are placed on whole orbits of the symmetry while they fit,
+// the rest goes to random cells
+impl MinePlacement for SymmetricPlacement {
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let options = field.options();
+        let per_cell = options.mines_per_cell as usize;
+        let mut rng = rand::rng();
+
+        let free_set: HashSet<_> = free.iter().copied().collect();
+        let mut orbits: Vec<Vec<(usize, usize)>> = Vec::new();
+
+        for &(x, y) in free {
+            let layer = x / options.cols * options.cols;
+            let local_x = x % options.cols;
+
+            let image = match self.0 {
+                Symmetry::Mirror => (layer + options.cols - 1 - local_x, y),
+                Symmetry::Rotational => (layer + options.cols - 1 - local_x, options.rows - 1 - y),
+            };
+
+            if !free_set.contains(&image) || (x, y) > image {
+                continue;
+            }
+
+            let orbit = if image == (x, y) {
+                vec![(x, y)]
+            } else {
+                vec![(x, y), image]
+            };
+
+            orbits.extend(std::iter::repeat_n(orbit, per_cell));
+        }
+
+        orbits.shuffle(&mut rng);
+
+        let mut mines = Vec::new();
+        let mut left = options.mines;
+
+        for orbit in orbits {
+            if orbit.len() <= left {
+                left -= orbit.len();
+                mines.extend(orbit);
+            }
+        }
+
+        let mut rest: Vec<_> = free
+            .iter()
+            .flat_map(|&coords| {
+                let placed = mines.iter().filter(|&&mine| mine == coords).count();
+                std::iter::repeat_n(coords, per_cell - placed)
+            })
+            .collect();
+
+        rest.shuffle(&mut rng);
+        mines.extend(rest.into_iter().take(left));
+
+        mines
+    }
+}
+
+impl ClusteredPlacement {
+    // how much each mined neighbour adds to the chance of a cell
+    const PULL: usize = 4;
+}
+
+impl MinePlacement for ClusteredPlacement {
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let options = field.options();
+        let per_cell = options.mines_per_cell as usize;
+        let mut rng = rand::rng();
+
+        let indices: HashMap<_, _> = free.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+
+        let mut weights = vec![1; free.len()];
+        let mut placed = vec![0; free.len()];
+        let mut mines = Vec::with_capacity(options.mines);
+
+        for _ in 0..options.mines {
+            let total: usize = weights.iter().sum();
+
+            if total == 0 {
+                break;
+            }
+
+            let mut pick = rng.random_range(0..total);
+            let index = weights
+                .iter()
+                .position(|&weight| {
+                    if pick < weight {
+                        return true;
+                    }
+
+                    pick -= weight;
+                    false
+                })
+                .unwrap_or(0);
+
+            let (x, y) = free[index];
+            mines.push((x, y));
+            placed[index] += 1;
+
+            if placed[index] == per_cell {
+                weights[index] = 0;
+            }
+
+            for coords in field.get_coords_around(x, y) {
+                if let Some(&index) = indices.get(&coords) {
+                    if placed[index] < per_cell {
+                        weights[index] += Self::PULL;
+                    }
+                }
+            }
+        }
+
+        mines
+    }
+}
+
+// one line per row across all layers, '*' marks a mine, digits mark
+// cells holding several of them and '.' the empty ones
+impl LayoutPlacement {
+    pub fn load(path: impl AsRef<Path>) -> DynResult<Self> {
+        let text = fs::read_to_string(path)?;
+
+        Ok(Self::from_text(&text)?)
+    }
+
+    pub fn from_mines(mines: Vec<(usize, usize)>) -> Self {
+        Self(mines)
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, String> {
+        let mut mines = Vec::new();
+
+        for (y, line) in text.lines().enumerate() {
+            for (x, cell) in line.trim_end().chars().enumerate() {
+                let count = match cell {
+                    '*' => 1,
+                    '.' => 0,
+                    _ => cell.to_digit(10).ok_or_else(|| {
+                        format!(
+                            "line {}, column {}: '{}' is neither '*', '.' nor a digit",
+                            y + 1,
+                            x + 1,
+                            cell
+                        )
+                    })? as usize,
+                };
+
+                mines.extend(std::iter::repeat_n((x, y), count));
+            }
+        }
+
+        Ok(Self(mines))
+    }
+
+    // the layout as a placement that can be picked in FieldOptions; the mines are
+    // leaked, which is fine for the few layouts read while the game runs
+    pub fn into_placement(self) -> Placement {
+        Placement::Layout(self.0.leak())
+    }
+}
+
+impl MinePlacement for LayoutPlacement {
+    // mines within the area of the first click are moved to random free cells
+    // with room left, the rest stay where the layout puts them
+    fn place(&mut self, field: &MineField, free: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let options = field.options();
+        let per_cell = options.mines_per_cell as usize;
+        let free_set: HashSet<_> = free.iter().copied().collect();
+
+        let mut counts: HashMap<_, usize> = HashMap::new();
+        let mut moved = 0;
+
+        for &(x, y) in &self.0 {
+            if free_set.contains(&(x, y)) {
+                *counts.entry((x, y)).or_default() += 1;
+            } else if x < options.total_cols() && y < options.rows && !options.is_void(x, y) {
+                moved += 1;
+            }
+        }
+
+        let mut mines: Vec<_> = counts
+            .iter()
+            .flat_map(|(&coords, &count)| std::iter::repeat_n(coords, count.min(per_cell)))
+            .collect();
+
+        let mut slots: Vec<_> = free
+            .iter()
+            .flat_map(|coords| {
+                let count = counts.get(coords).copied().unwrap_or(0);
+                std::iter::repeat_n(*coords, per_cell.saturating_sub(count))
+            })
+            .collect();
+
+        slots.shuffle(&mut rand::rng());
+        mines.extend(slots.into_iter().take(moved));
+
+        mines
+    }
+}
+
+fn place_uniform(
+    rng: &mut impl Rng,
+    field: &MineField,
+    free: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    let options = field.options();
+    let per_cell = options.mines_per_cell as usize;
+
+    // every cell offers a slot for each mine it can hold
+    sample(
+        rng,
+        free.len() * per_cell,
+        options.mines.min(free.len() * per_cell),
+    )
+    .into_iter()
+    .map(|slot| free[slot / per_cell])
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{CellKind, FieldOptions, FirstClick};
+    use super::*;
+
+    #[test]
+    fn layouts_leave_the_first_click_free() {
+        let mut field = MineField::new(FieldOptions {
+            cols: 9,
+            rows: 9,
+            layers: 1,
+            mines: 10,
+            mines_per_cell: 1,
+            first_click: FirstClick::Opening,
+            ..Default::default()
+        });
+
+        // the whole block around the middle is mined, plus a corner
+        let mut mines: Vec<_> = (3..6).flat_map(|x| (3..6).map(move |y| (x, y))).collect();
+        mines.push((0, 0));
+
+        let placed = field.place_mines(4, 4, &mut LayoutPlacement::from_mines(mines));
+        let safe_area = field.get_safe_area(4, 4);
+
+        assert_eq!(placed, 10);
+        assert!(matches!(field.cells[0][0].kind, CellKind::Mined(1)));
+        assert!(safe_area
+            .iter()
+            .all(|&(x, y)| !matches!(field.cells[x][y].kind, CellKind::Mined(_))));
+    }
+
+    #[test]
+    fn malformed_layouts_fail() {
+        assert!(LayoutPlacement::from_text("*.\n.2*\n").is_ok_and(|layout| layout.0.len() == 4));
+        assert!(LayoutPlacement::from_text("*.\n.x\n")
+            .is_err_and(|e| e.starts_with("line 2, column 2")));
+    }
+}
@@ -0,0 +1,438 @@
+use super::{
+    BoardShape, Difficulty, FieldOptions, FirstClick, LayoutPlacement, Luck, MineField,
+    Neighbourhood, OffsetMask, Placement, Symmetry, Tiling, Topology,
+};
+use crate::DynResult;
+use std::{fs, path::Path};
+
+// plain-text board preset, one "key value" pair per line:
+//
+//     cols 16
+//     rows 16
+//     layers 1
+//     mines 40
+//     mines_per_cell 1
+//     tiling square
+//     topology torus
+//     neighbourhood custom
+//     first_click radius 2
+//     placement seeded 42
+//     luck kind
+//     difficulty score 40 80
+//     mask ...#...
+//     mask ..#.#..
+//     ...
+//
+// a custom neighbourhood takes 7 mask lines of 7 cells each,
+// '#' marks a neighbour and the center cell is ignored
+//
+// irregular boards take "shape" lines, one per row, where '.' marks a void cell,
+// or a "silhouette" line with the path to a plain-text or .png silhouette;
+// both set cols and rows to the size of the shape
+//
+// "placement layout" takes its mines from "layout" lines in the format read by
+// LayoutPlacement, "placement layout <path>" from a layout file
+impl FieldOptions {
+    pub fn load_preset(path: impl AsRef<Path>) -> DynResult<Self> {
+        let text = fs::read_to_string(path)?;
+
+        Ok(Self::from_preset(&text)?)
+    }
+
+    pub fn save_preset(&self, path: impl AsRef<Path>) -> DynResult<()> {
+        fs::write(path, self.to_preset())?;
+
+        Ok(())
+    }
+
+    pub fn from_preset(text: &str) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut mask_rows = Vec::new();
+        let mut shape_rows = Vec::new();
+        let mut layout_rows = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let error = |message: &str| format!("line {}: {}", index + 1, message);
+
+            let (key, value) = line
+                .split_once(char::is_whitespace)
+                .map(|(key, value)| (key, value.trim()))
+                .ok_or_else(|| error("expected a key and a value"))?;
+
+            let parse_number = || {
+                value
+                    .parse()
+                    .map_err(|_| error(&format!("'{value}' is not a number")))
+            };
+
+            match key {
+                "cols" => options.cols = parse_number()?,
+                "rows" => options.rows = parse_number()?,
+                "layers" => options.layers = parse_number()?,
+                "mines" => options.mines = parse_number()?,
+                "mines_per_cell" => {
+                    options.mines_per_cell = u8::try_from(parse_number()?).unwrap_or(u8::MAX)
+                }
+                "tiling" => {
+                    options.tiling = match value {
+                        "square" => Tiling::Square,
+                        "hex" => Tiling::Hex,
+                        "triangle" => Tiling::Triangle,
+                        _ => return Err(error(&format!("unknown tiling '{value}'"))),
+                    }
+                }
+                "topology" => {
+                    options.topology = match value {
+                        "plane" => Topology::Plane,
+                        "torus" => Topology::Torus,
+                        _ => return Err(error(&format!("unknown topology '{value}'"))),
+                    }
+                }
+                "neighbourhood" => {
+                    options.neighbourhood = match value {
+                        "adjacent" => Neighbourhood::Adjacent,
+                        "orthogonal" => Neighbourhood::Orthogonal,
+                        "knight" => Neighbourhood::Knight,
+                        "radius2" => Neighbourhood::Radius2,
+                        "custom" => Neighbourhood::Custom(OffsetMask::default()),
+                        _ => return Err(error(&format!("unknown neighbourhood '{value}'"))),
+                    }
+                }
+                "first_click" => {
+                    options.first_click = match value.split_once(char::is_whitespace) {
+                        None if value == "cell" => FirstClick::Cell,
+                        None if value == "opening" => FirstClick::Opening,
+                        Some(("radius", radius)) => match radius.trim().parse() {
+                            Ok(radius) => FirstClick::Radius(radius),
+                            Err(_) => return Err(error(&format!("'{radius}' is not a radius"))),
+                        },
+                        _ => return Err(error(&format!("unknown first click policy '{value}'"))),
+                    }
+                }
+                "placement" => {
+                    options.placement = match value.split_once(char::is_whitespace) {
+                        None if value == "uniform" => Placement::Uniform,
+                        None if value == "no_guess" => Placement::NoGuess,
+                        None if value == "mirror" => Placement::Symmetric(Symmetry::Mirror),
+                        None if value == "rotational" => Placement::Symmetric(Symmetry::Rotational),
+                        None if value == "clustered" => Placement::Clustered,
+                        // filled in from the layout lines
+                        None if value == "layout" => Placement::Layout(&[]),
+                        Some(("layout", path)) => LayoutPlacement::load(path.trim())
+                            .map_err(|e| error(&e.to_string()))?
+                            .into_placement(),
+                        Some(("seeded", seed)) => match seed.trim().parse() {
+                            Ok(seed) => Placement::Seeded(seed),
+                            Err(_) => return Err(error(&format!("'{seed}' is not a seed"))),
+                        },
+                        _ => return Err(error(&format!("unknown placement '{value}'"))),
+                    }
+                }
+                "luck" => {
+                    options.luck = match value {
+                        "fixed" => Luck::Fixed,
+                        "kind" => Luck::Kind,
+                        "mean" => Luck::Mean,
+                        _ => return Err(error(&format!("unknown luck '{value}'"))),
+                    }
+                }
+                "difficulty" => {
+                    options.difficulty = match value.split_once(char::is_whitespace) {
+                        None if value == "any" => Difficulty::Any,
+                        None if value == "easy" => Difficulty::EasyLogic,
+                        None if value == "advanced" => Difficulty::Advanced,
+                        Some(("score", bounds)) => {
+                            let bounds: Vec<_> =
+                                bounds.split_whitespace().map(str::parse).collect();
+
+                            match bounds[..] {
+                                [Ok(min), Ok(max)] if min <= max => Difficulty::Score(min, max),
+                                _ => return Err(error("score takes a minimum and a maximum")),
+                            }
+                        }
+                        _ => return Err(error(&format!("unknown difficulty '{value}'"))),
+                    }
+                }
+                "mask" if value.chars().count() != 7 => {
+                    return Err(error("mask rows must be 7 cells wide"));
+                }
+                "mask" if mask_rows.len() == 7 => {
+                    return Err(error("mask has more than 7 rows"));
+                }
+                "mask" => mask_rows.push(value),
+                "shape" => shape_rows.push(value),
+                "layout" => layout_rows.push(value),
+                "silhouette" => {
+                    let (shape, cols, rows) =
+                        BoardShape::load(value).map_err(|e| error(&e.to_string()))?;
+
+                    options.shape = shape;
+                    options.cols = cols;
+                    options.rows = rows;
+                }
+                _ => return Err(error(&format!("unknown key '{key}'"))),
+            }
+        }
+
+        if let Neighbourhood::Custom(ref mut mask) = options.neighbourhood {
+            if mask_rows.len() != 7 {
+                return Err(String::from("custom neighbourhood needs 7 mask rows"));
+            }
+
+            for (dy, row) in (-OffsetMask::RADIUS..).zip(mask_rows) {
+                for (dx, cell) in (-OffsetMask::RADIUS..).zip(row.chars()) {
+                    if cell == '#' {
+                        mask.insert(dx, dy);
+                    }
+                }
+            }
+        }
+
+        if !shape_rows.is_empty() {
+            let (shape, cols, rows) = BoardShape::from_rows(shape_rows)?;
+
+            options.shape = shape;
+            options.cols = cols;
+            options.rows = rows;
+        }
+
+        if !layout_rows.is_empty() {
+            let Placement::Layout([]) = options.placement else {
+                return Err(String::from(
+                    "layout lines need \"placement layout\" without a file",
+                ));
+            };
+
+            options.placement = LayoutPlacement::from_text(&layout_rows.join("\n"))
+                .map_err(|e| format!("layout {e}"))?
+                .into_placement();
+        }
+
+        // the same limit the field clamps to, but told instead of clamped
+        let slots = MineField::new(options).max_mines();
+
+        if options.mines > slots {
+            return Err(format!(
+                "{} mines don't fit, at most {} leave room for the first click",
+                options.mines, slots
+            ));
+        }
+
+        Ok(options)
+    }
+
+    pub fn to_preset(&self) -> String {
+        let tiling = match self.tiling {
+            Tiling::Square => "square",
+            Tiling::Hex => "hex",
+            Tiling::Triangle => "triangle",
+        };
+
+        let topology = match self.topology {
+            Topology::Plane => "plane",
+            Topology::Torus => "torus",
+        };
+
+        let neighbourhood = match self.neighbourhood {
+            Neighbourhood::Adjacent => "adjacent",
+            Neighbourhood::Orthogonal => "orthogonal",
+            Neighbourhood::Knight => "knight",
+            Neighbourhood::Radius2 => "radius2",
+            Neighbourhood::Custom(_) => "custom",
+        };
+
+        let first_click = match self.first_click {
+            FirstClick::Cell => String::from("cell"),
+            FirstClick::Opening => String::from("opening"),
+            FirstClick::Radius(radius) => format!("radius {radius}"),
+        };
+
+        let placement = match self.placement {
+            Placement::Uniform => String::from("uniform"),
+            Placement::Seeded(seed) => format!("seeded {seed}"),
+            Placement::NoGuess => String::from("no_guess"),
+            Placement::Symmetric(Symmetry::Mirror) => String::from("mirror"),
+            Placement::Symmetric(Symmetry::Rotational) => String::from("rotational"),
+            Placement::Clustered => String::from("clustered"),
+            Placement::Layout(_) => String::from("layout"),
+        };
+
+        let luck = match self.luck {
+            Luck::Fixed => "fixed",
+            Luck::Kind => "kind",
+            Luck::Mean => "mean",
+        };
+
+        let difficulty = match self.difficulty {
+            Difficulty::Any => String::from("any"),
+            Difficulty::EasyLogic => String::from("easy"),
+            Difficulty::Advanced => String::from("advanced"),
+            Difficulty::Score(min, max) => format!("score {min} {max}"),
+        };
+
+        let mut lines = vec![
+            format!("cols {}", self.cols),
+            format!("rows {}", self.rows),
+            format!("layers {}", self.layers),
+            format!("mines {}", self.mines),
+            format!("mines_per_cell {}", self.mines_per_cell),
+            format!("tiling {tiling}"),
+            format!("topology {topology}"),
+            format!("neighbourhood {neighbourhood}"),
+            format!("first_click {first_click}"),
+            format!("placement {placement}"),
+            format!("luck {luck}"),
+            format!("difficulty {difficulty}"),
+        ];
+
+        if !self.shape.is_empty() {
+            for row in self.shape.to_rows(self.cols, self.rows) {
+                lines.push(format!("shape {row}"));
+            }
+        }
+
+        if let Placement::Layout(mines) = self.placement {
+            for y in 0..self.rows {
+                let row: String = (0..self.total_cols())
+                    .map(
+                        |x| match mines.iter().filter(|&&coords| coords == (x, y)).count() {
+                            0 => '.',
+                            1 => '*',
+                            count => char::from_digit(count.min(9) as u32, 10).unwrap_or('9'),
+                        },
+                    )
+                    .collect();
+
+                lines.push(format!("layout {row}"));
+            }
+        }
+
+        if let Neighbourhood::Custom(mask) = self.neighbourhood {
+            let radius = OffsetMask::RADIUS;
+
+            for dy in -radius..=radius {
+                let row: String = (-radius..=radius)
+                    .map(|dx| match (dx, dy) {
+                        (0, 0) => 'o',
+                        _ if mask.contains(dx, dy) => '#',
+                        _ => '.',
+                    })
+                    .collect();
+
+                lines.push(format!("mask {row}"));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(lines: &[&str]) -> Result<FieldOptions, String> {
+        FieldOptions::from_preset(&lines.join("\n"))
+    }
+
+    #[test]
+    fn mines_leave_room_for_the_first_click() {
+        let cases = [
+            ("cell", 1, 80),
+            ("opening", 1, 72),
+            ("radius 2", 1, 56),
+            ("opening", 2, 144),
+        ];
+
+        for (first_click, per_cell, max) in cases {
+            let with_mines = |mines: usize| {
+                preset(&[
+                    "cols 9",
+                    "rows 9",
+                    &format!("mines {mines}"),
+                    &format!("mines_per_cell {per_cell}"),
+                    &format!("first_click {first_click}"),
+                ])
+            };
+
+            assert!(with_mines(max).is_ok_and(|options| options.mines == max));
+            assert!(with_mines(max + 1).is_err());
+        }
+    }
+
+    #[test]
+    fn shapes_count_their_void_cells() {
+        let mut lines = vec!["mines 63", "first_click opening", "shape ........."];
+        lines.extend(["shape #########"; 8]);
+
+        assert!(preset(&lines).is_ok());
+
+        lines[0] = "mines 64";
+        assert!(preset(&lines).is_err());
+    }
+
+    #[test]
+    fn unknown_values_fail() {
+        assert!(preset(&["tiling round"]).is_err_and(|e| e.starts_with("line 1")));
+        assert!(preset(&["cols 9", "rows nine"]).is_err_and(|e| e.starts_with("line 2")));
+        assert!(preset(&["difficulty score 80 40"]).is_err());
+        assert!(preset(&["neighbourhood custom", "mask ...#..."]).is_err());
+        assert!(preset(&["mask ..#.."]).is_err());
+        assert!(preset(&["colour blue"]).is_err());
+    }
+
+    #[test]
+    fn layouts_are_read_inline() {
+        let mut lines = vec!["cols 9", "rows 9", "layers 1", "mines_per_cell 2"];
+        lines.extend(["placement layout", "layout *........", "layout ..2......"]);
+        lines.extend(["layout ........."; 7]);
+
+        let Ok(options) = preset(&lines) else {
+            panic!("the layout doesn't read");
+        };
+
+        assert!(options.placement == Placement::Layout(&[(0, 0), (2, 1), (2, 1)]));
+        assert!(FieldOptions::from_preset(&options.to_preset())
+            .is_ok_and(|read| read.placement == options.placement));
+
+        lines[4] = "placement uniform";
+        assert!(preset(&lines).is_err());
+        assert!(preset(&["placement layout", "layout *.?"]).is_err_and(|e| e.contains("column 3")));
+    }
+
+    #[test]
+    fn presets_round_trip() {
+        let mut mask = OffsetMask::default();
+        mask.insert(1, 0);
+        mask.insert(-2, 3);
+
+        let options = FieldOptions {
+            cols: 20,
+            rows: 12,
+            layers: 3,
+            mines: 50,
+            mines_per_cell: 2,
+            tiling: Tiling::Square,
+            topology: Topology::Torus,
+            neighbourhood: Neighbourhood::Custom(mask),
+            first_click: FirstClick::Radius(2),
+            placement: Placement::Seeded(42),
+            luck: Luck::Kind,
+            difficulty: Difficulty::Score(40, 80),
+            ..Default::default()
+        };
+
+        let Ok(read) = FieldOptions::from_preset(&options.to_preset()) else {
+            panic!("the preset doesn't read back");
+        };
+
+        assert_eq!(read.to_preset(), options.to_preset());
+        assert!(read.neighbourhood == options.neighbourhood);
+    }
+}
@@ -0,0 +1,496 @@
+use super::{CellKind, CellState, MineField};
+use std::collections::HashMap;
+
+// hidden cells next to revealed numbers and the rest of the hidden cells
+pub(super) struct Frontier {
+    pub cells: Vec<(usize, usize)>,
+    pub groups: Vec<Group>,
+    pub interior: Vec<(usize, usize)>,
+}
+
+// cells of the frontier split into groups that share no number
+pub(super) struct Group {
+    // indices into the frontier
+    pub cells: Vec<usize>,
+    // solutions by the number of mines they put into the group
+    pub counts: Vec<f64>,
+    // for each mine count, the solutions mining each cell of the group
+    pub mined: Vec<Vec<f64>>,
+    // the numbers seeing the group, in indices into its cells
+    constraints: Vec<Constraint>,
+}
+
+struct Constraint {
+    cells: Vec<usize>,
+    mines: usize,
+}
+
+impl MineField {
+    // nodes visited in all groups before giving up
+    const PROBABILITY_BUDGET: usize = 2_000_000;
+
+    // the chance of every hidden cell holding a mine, given the revealed numbers
+    // and the total number of mines; flags are not trusted, revealed cells get 0;
+    // None when the numbers contradict each other or the board is too open to count
+    pub fn get_probabilities(&self) -> Option<Vec<Vec<f64>>> {
+        let per_cell = self.options.mines_per_cell as usize;
+        let Frontier {
+            cells: frontier,
+            groups,
+            interior,
+        } = self.get_frontier_groups()?;
+
+        let total = self.count_mines();
+
+        // ways to spread the remaining mines over the interior, and over it
+        // with one cell less, both in natural logarithms
+        let (ln_ways, ln_ways_but_one) = count_spreads(interior.len(), per_cell, total);
+
+        let all = convolve(groups.iter().map(|group| &group.counts[..]));
+        let weight = |mines: usize, ln: &[f64]| ln.get(total.checked_sub(mines)?).copied();
+
+        // everything is scaled by the largest term so the weights stay in range
+        let scale = (0..all.len())
+            .filter(|&k| all[k] > 0.0)
+            .filter_map(|k| Some(all[k].ln() + weight(k, &ln_ways)?))
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        if scale == f64::NEG_INFINITY {
+            return None;
+        }
+
+        let term = |count: f64, mines: usize, ln: &[f64]| match weight(mines, ln) {
+            Some(ln) if count > 0.0 => (count.ln() + ln - scale).exp(),
+            _ => 0.0,
+        };
+
+        let sum = (0..all.len())
+            .map(|k| term(all[k], k, &ln_ways))
+            .sum::<f64>();
+        let mut probabilities = vec![vec![0.0; self.options.rows]; self.options.total_cols()];
+
+        for (i, group) in groups.iter().enumerate() {
+            let others = convolve(
+                groups
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, group)| &group.counts[..]),
+            );
+
+            for (index, &cell) in group.cells.iter().enumerate() {
+                let mut mined = 0.0;
+
+                for (k, counts) in group.mined.iter().enumerate() {
+                    for (rest, &other) in others.iter().enumerate() {
+                        mined += term(counts[index] * other, k + rest, &ln_ways);
+                    }
+                }
+
+                let (x, y) = frontier[cell];
+                probabilities[x][y] = mined / sum;
+            }
+        }
+
+        // an interior cell is safe in the layouts spreading the mines over the others
+        let safe = (0..all.len())
+            .map(|k| term(all[k], k, &ln_ways_but_one))
+            .sum::<f64>();
+
+        for &(x, y) in &interior {
+            probabilities[x][y] = 1.0 - safe / sum;
+        }
+
+        Some(probabilities)
+    }
+
+    // every group is counted up front; None when a revealed cell is mined
+    // or the groups take more than the budget to count
+    pub(super) fn get_frontier_groups(&self) -> Option<Frontier> {
+        let per_cell = self.options.mines_per_cell as usize;
+        let is_revealed = |x: usize, y: usize| self.cells[x][y].state == CellState::Revealed;
+
+        let mut frontier = Vec::new();
+        let mut indices = HashMap::new();
+        let mut constraints = Vec::new();
+
+        for (x, y) in self.get_coords_all().filter(|&(x, y)| is_revealed(x, y)) {
+            let CellKind::Num(number) = self.cells[x][y].kind else {
+                return None;
+            };
+
+            let cells: Vec<_> = self
+                .get_coords_around(x, y)
+                .filter(|&(x, y)| !is_revealed(x, y))
+                .map(|coords| {
+                    *indices.entry(coords).or_insert_with(|| {
+                        frontier.push(coords);
+                        frontier.len() - 1
+                    })
+                })
+                .collect();
+
+            if !cells.is_empty() {
+                constraints.push(Constraint {
+                    cells,
+                    mines: number as usize,
+                });
+            }
+        }
+
+        let interior: Vec<_> = self
+            .get_coords_all()
+            .filter(|&(x, y)| !is_revealed(x, y) && !indices.contains_key(&(x, y)))
+            .collect();
+
+        let mut budget = Self::PROBABILITY_BUDGET;
+
+        let groups = split_groups(frontier.len(), &constraints)
+            .into_iter()
+            .map(|cells| count_group(cells, &constraints, per_cell, &mut budget))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Frontier {
+            cells: frontier,
+            groups,
+            interior,
+        })
+    }
+
+    pub(super) fn count_mines(&self) -> usize {
+        self.get_coords_all()
+            .map(|(x, y)| match self.cells[x][y].kind {
+                CellKind::Mined(n) => n as usize,
+                CellKind::Num(_) => 0,
+            })
+            .sum()
+    }
+}
+
+// frontier cells are linked when some number sees both of them
+fn split_groups(count: usize, constraints: &[Constraint]) -> Vec<Vec<usize>> {
+    let mut group_of = vec![usize::MAX; count];
+    let mut groups = Vec::new();
+
+    for start in 0..count {
+        if group_of[start] != usize::MAX {
+            continue;
+        }
+
+        let id = groups.len();
+        let mut cells = vec![start];
+        let mut stack = vec![start];
+        group_of[start] = id;
+
+        while let Some(cell) = stack.pop() {
+            for constraint in constraints.iter().filter(|c| c.cells.contains(&cell)) {
+                for &other in &constraint.cells {
+                    if group_of[other] == usize::MAX {
+                        group_of[other] = id;
+                        cells.push(other);
+                        stack.push(other);
+                    }
+                }
+            }
+        }
+
+        groups.push(cells);
+    }
+
+    groups
+}
+
+// enumerates every way of filling the group that agrees with its numbers
+fn count_group(
+    cells: Vec<usize>,
+    constraints: &[Constraint],
+    per_cell: usize,
+    budget: &mut usize,
+) -> Option<Group> {
+    let local: HashMap<_, _> = cells.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+
+    let constraints: Vec<_> = constraints
+        .iter()
+        .filter(|c| local.contains_key(&c.cells[0]))
+        .map(|c| Constraint {
+            cells: c.cells.iter().map(|cell| local[cell]).collect(),
+            mines: c.mines,
+        })
+        .collect();
+
+    let mut counts = vec![0.0; cells.len() * per_cell + 1];
+    let mut mined = vec![vec![0.0; cells.len()]; cells.len() * per_cell + 1];
+
+    let mut visit = |values: &[usize]| {
+        let placed = values.iter().sum::<usize>();
+        counts[placed] += 1.0;
+
+        for (cell, &value) in values.iter().enumerate() {
+            if value > 0 {
+                mined[placed][cell] += 1.0;
+            }
+        }
+
+        true
+    };
+
+    if !GroupSearch::new(&constraints, cells.len(), per_cell, budget, &mut visit).run(0) {
+        return None;
+    }
+
+    Some(Group {
+        cells,
+        counts,
+        mined,
+        constraints,
+    })
+}
+
+impl Group {
+    // calls `visit` with the mines of every cell in each solution of the group
+    // until it returns false; returns false when stopped that way
+    pub fn walk(&self, per_cell: usize, visit: &mut dyn FnMut(&[usize]) -> bool) -> bool {
+        let mut budget = usize::MAX;
+
+        GroupSearch::new(
+            &self.constraints,
+            self.cells.len(),
+            per_cell,
+            &mut budget,
+            visit,
+        )
+        .run(0)
+    }
+}
+
+struct GroupSearch<'a> {
+    per_cell: usize,
+    values: Vec<usize>,
+    links: Vec<Vec<usize>>,
+    // per constraint: mines assigned, cells without a value and mines wanted
+    assigned: Vec<usize>,
+    open: Vec<usize>,
+    mines: Vec<usize>,
+    budget: &'a mut usize,
+    visit: &'a mut dyn FnMut(&[usize]) -> bool,
+}
+
+impl<'a> GroupSearch<'a> {
+    fn new(
+        constraints: &[Constraint],
+        cells: usize,
+        per_cell: usize,
+        budget: &'a mut usize,
+        visit: &'a mut dyn FnMut(&[usize]) -> bool,
+    ) -> Self {
+        let mut links = vec![Vec::new(); cells];
+
+        for (c, constraint) in constraints.iter().enumerate() {
+            for &member in &constraint.cells {
+                links[member].push(c);
+            }
+        }
+
+        Self {
+            per_cell,
+            values: vec![0; cells],
+            links,
+            assigned: vec![0; constraints.len()],
+            open: constraints.iter().map(|c| c.cells.len()).collect(),
+            mines: constraints.iter().map(|c| c.mines).collect(),
+            budget,
+            visit,
+        }
+    }
+
+    // returns false when the budget runs out or the visitor stops the search
+    fn run(&mut self, index: usize) -> bool {
+        if index == self.values.len() {
+            return (self.visit)(&self.values);
+        }
+
+        if *self.budget == 0 {
+            return false;
+        }
+
+        *self.budget -= 1;
+
+        for value in 0..=self.per_cell {
+            let fits = self.links[index].iter().all(|&c| {
+                let assigned = self.assigned[c] + value;
+                let open = self.open[c] - 1;
+
+                assigned <= self.mines[c] && assigned + open * self.per_cell >= self.mines[c]
+            });
+
+            if !fits {
+                continue;
+            }
+
+            for &c in &self.links[index] {
+                self.assigned[c] += value;
+                self.open[c] -= 1;
+            }
+
+            self.values[index] = value;
+            let finished = self.run(index + 1);
+
+            for &c in &self.links[index] {
+                self.assigned[c] -= value;
+                self.open[c] += 1;
+            }
+
+            if !finished {
+                return false;
+            }
+        }
+
+        self.values[index] = 0;
+        true
+    }
+}
+
+// number of ways of putting k mines into the groups altogether, for every k
+fn convolve<'a>(groups: impl Iterator<Item = &'a [f64]>) -> Vec<f64> {
+    groups.fold(vec![1.0], |all, counts| {
+        let mut result = vec![0.0; all.len() + counts.len() - 1];
+
+        for (i, &a) in all.iter().enumerate() {
+            for (j, &b) in counts.iter().enumerate() {
+                result[i + j] += a * b;
+            }
+        }
+
+        result
+    })
+}
+
+// logarithms of the ways of spreading 0..=max mines over `cells` cells
+// and over one cell less, each cell holding up to `per_cell` of them
+fn count_spreads(cells: usize, per_cell: usize, max: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut ln_ways = vec![f64::NEG_INFINITY; max + 1];
+    ln_ways[0] = 0.0;
+
+    let mut previous = ln_ways.clone();
+
+    for _ in 0..cells {
+        previous = ln_ways.clone();
+
+        for (mines, ln) in ln_ways.iter_mut().enumerate() {
+            *ln = (0..=per_cell.min(mines))
+                .map(|value| previous[mines - value])
+                .fold(f64::NEG_INFINITY, add_ln);
+        }
+    }
+
+    if cells == 0 {
+        previous = vec![f64::NEG_INFINITY; max + 1];
+    }
+
+    (ln_ways, previous)
+}
+
+// ln(e^a + e^b) without leaving the range of f64
+pub(super) fn add_ln(a: f64, b: f64) -> f64 {
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+
+    if low == f64::NEG_INFINITY {
+        return high;
+    }
+
+    high + (low - high).exp().ln_1p()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{brute_force_layouts, field_with_hidden};
+    use super::*;
+
+    // how often each cell holds a mine over all the layouts
+    fn brute_force_probabilities(field: &MineField) -> Vec<Vec<f64>> {
+        let layouts = brute_force_layouts(field);
+        let mut probabilities = vec![vec![0.0; field.options.rows]; field.options.total_cols()];
+
+        for layout in &layouts {
+            let mut cells = layout.clone();
+            cells.dedup();
+
+            for (x, y) in cells {
+                probabilities[x][y] += 1.0 / layouts.len() as f64;
+            }
+        }
+
+        probabilities
+    }
+
+    fn assert_close(found: &[Vec<f64>], expected: &[Vec<f64>]) {
+        for (found, expected) in found.iter().flatten().zip(expected.iter().flatten()) {
+            assert!((found - expected).abs() < 1e-9, "{found} != {expected}");
+        }
+    }
+
+    #[test]
+    fn frontier_only_matches_brute_force() {
+        let mines = [(4, 1), (4, 2), (1, 4), (6, 4), (4, 7)];
+        let field = field_with_hidden(1, &mines, |x, y| x == 4 || y == 4);
+
+        let Some(frontier) = field.get_frontier_groups() else {
+            panic!("the frontier can't be counted");
+        };
+        assert!(frontier.interior.is_empty());
+
+        let probabilities = field.get_probabilities().unwrap_or_default();
+        assert_close(&probabilities, &brute_force_probabilities(&field));
+
+        let sum = probabilities.iter().flatten().sum::<f64>();
+        assert!((sum - mines.len() as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interior_matches_brute_force() {
+        let mines = [(0, 0), (1, 2), (3, 1), (2, 3), (0, 3)];
+        let field = field_with_hidden(1, &mines, |x, y| x < 4 && y < 4);
+
+        let probabilities = field.get_probabilities().unwrap_or_default();
+        assert_close(&probabilities, &brute_force_probabilities(&field));
+
+        let sum = probabilities.iter().flatten().sum::<f64>();
+        assert!((sum - mines.len() as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn multimines_match_brute_force() {
+        let mines = [(0, 0), (0, 0), (2, 1), (1, 2)];
+        let field = field_with_hidden(2, &mines, |x, y| x < 3 && y < 3);
+
+        let probabilities = field.get_probabilities().unwrap_or_default();
+        assert_close(&probabilities, &brute_force_probabilities(&field));
+    }
+
+    #[test]
+    fn no_frontier_is_uniform() {
+        let mines: Vec<_> = (0..9).map(|x| (x, x * 4 % 9)).collect();
+        let field = field_with_hidden(1, &mines, |_, _| true);
+
+        let Some(frontier) = field.get_frontier_groups() else {
+            panic!("the frontier can't be counted");
+        };
+        assert!(frontier.groups.is_empty());
+
+        let probabilities = field.get_probabilities().unwrap_or_default();
+        let sum = probabilities.iter().flatten().sum::<f64>();
+
+        assert!((sum - 9.0).abs() < 1e-9);
+        assert!(probabilities
+            .iter()
+            .flatten()
+            .all(|&p| (p - 9.0 / 81.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn revealed_mines_give_nothing() {
+        let field = field_with_hidden(1, &[(4, 4)], |_, _| false);
+
+        assert!(field.get_probabilities().is_none());
+    }
+}
@@ -0,0 +1,314 @@
+use super::{
+    solver::{Rule, Solver},
+    BoardShape, CellKind, CellState, Difficulty, FieldOptions, FirstClick, MineField,
+    UniformPlacement,
+};
+use rand::{seq::SliceRandom, Rng};
+
+// a hand-made board, one line per row: '*' is a hidden mine, '#' a hidden safe cell,
+// 'o' or a digit a revealed one and '.' or a space a void cell
+pub struct Puzzle {
+    pub name: String,
+    rows: Vec<String>,
+}
+
+impl Puzzle {
+    // puzzles of a pack are separated by blank lines,
+    // each may start with a `name ...` line
+    pub fn parse_pack(text: &str) -> Vec<Self> {
+        let mut puzzles = Vec::new();
+        let mut name = None;
+        let mut rows = Vec::new();
+
+        for line in text.lines().chain([""]) {
+            let line = line.trim_end();
+
+            if let Some(value) = line.strip_prefix("name ") {
+                name = Some(value.trim().to_string());
+                continue;
+            }
+
+            if !line.is_empty() {
+                rows.push(line.to_string());
+                continue;
+            }
+
+            if rows.is_empty() {
+                continue;
+            }
+
+            let name = name
+                .take()
+                .unwrap_or_else(|| format!("puzzle {}", puzzles.len() + 1));
+
+            puzzles.push(Self {
+                name,
+                rows: std::mem::take(&mut rows),
+            });
+        }
+
+        puzzles
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl MineField {
+    // the board is made at least as large as asked so all puzzles of a pack
+    // can share one window, the rest of it is void; fails when the numbers
+    // don't match the mines or the puzzle can't be solved without guessing
+    pub fn from_puzzle(puzzle: &Puzzle, cols: usize, rows: usize) -> Result<Self, String> {
+        let error = |message: &str| format!("{}: {}", puzzle.name, message);
+
+        let (shape, ..) =
+            BoardShape::from_rows(puzzle.rows.iter().map(String::as_str)).map_err(|e| error(&e))?;
+
+        let options = FieldOptions {
+            cols: cols.max(puzzle.width()),
+            rows: rows.max(puzzle.height()),
+            layers: 1,
+            mines_per_cell: 1,
+            shape,
+            first_click: FirstClick::Cell,
+            ..Default::default()
+        };
+
+        let mut field = Self::new(options);
+
+        let cells = || {
+            puzzle
+                .rows
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| row.chars().enumerate().map(move |(x, cell)| (x, y, cell)))
+        };
+
+        let mines: Vec<_> = cells()
+            .filter(|&(.., cell)| cell == '*')
+            .map(|(x, y, _)| (x, y))
+            .collect();
+
+        field.options.mines = field.set_mines(&mines);
+
+        for (x, y, cell) in cells() {
+            let number = match cell {
+                'o' => None,
+                '0'..='9' => cell.to_digit(10),
+                '*' | '#' | '.' | ' ' => continue,
+                _ => return Err(error(&format!("unknown cell '{cell}'"))),
+            };
+
+            let found = field.cells[x][y].kind;
+
+            if number.is_some_and(|number| found != CellKind::Num(number as u16)) {
+                return Err(error(&format!(
+                    "the number at ({x},{y}) doesn't match the mines"
+                )));
+            }
+
+            field.cells[x][y].state = CellState::Revealed;
+        }
+
+        if !Solver::new(&field).solve() {
+            return Err(error("can't be solved without guessing"));
+        }
+
+        Ok(field)
+    }
+}
+
+impl MineField {
+    // boards drawn before the last one is kept even if it misses the difficulty
+    const PUZZLE_ATTEMPTS: usize = 100;
+
+    // a random board of the size and mines asked for, with every safe cell revealed
+    // at first; the givens are then hidden in random order while the rest still
+    // proves every mine, so none of the ones left can go; EasyLogic keeps to
+    // single numbers and Advanced only takes boards needing the subset rule;
+    // scores rate games from the first click, so puzzles can't be asked for one
+    pub fn generate_puzzle(options: FieldOptions) -> Result<Self, String> {
+        let FieldOptions {
+            cols,
+            rows,
+            mines,
+            shape,
+            difficulty,
+            ..
+        } = options;
+
+        let options = FieldOptions {
+            cols,
+            rows,
+            layers: 1,
+            mines,
+            mines_per_cell: 1,
+            shape,
+            ..Default::default()
+        };
+
+        let max_rule = match difficulty {
+            Difficulty::EasyLogic => Rule::Single,
+            Difficulty::Any | Difficulty::Advanced => Rule::Subset,
+            Difficulty::Score(..) => {
+                return Err("puzzles can't be generated for a score".to_string())
+            }
+        };
+
+        let mut rng = rand::rng();
+        let mut field = Self::new(options);
+
+        for _ in 0..Self::PUZZLE_ATTEMPTS {
+            field = Self::new(options);
+
+            let usable: Vec<_> = field.get_coords_all().collect();
+            let (x, y) = usable[rng.random_range(0..usable.len())];
+
+            field.place_mines(x, y, &mut UniformPlacement);
+
+            field.hide_givens(max_rule, &mut rng);
+
+            let level = field.solve_puzzle(max_rule).unwrap_or(0);
+
+            if difficulty != Difficulty::Advanced || level == Rule::Subset as u8 {
+                break;
+            }
+        }
+
+        Ok(field)
+    }
+
+    // a puzzle with the mines of the board, keeping as few givens as still prove
+    // them all; puzzles have one layer and one mine per cell
+    pub fn make_puzzle(&self) -> Result<Self, String> {
+        if self.options.layers > 1 || self.options.mines_per_cell > 1 {
+            return Err("puzzles have one layer and one mine per cell".to_string());
+        }
+
+        let mut field = self.clone();
+
+        if !field.hide_givens(Rule::Subset, &mut rand::rng()) {
+            return Err("the mines can't be proved even with every number shown".to_string());
+        }
+
+        Ok(field)
+    }
+
+    // the board in the format read by from_puzzle, revealed cells show their numbers
+    pub fn to_puzzle(&self) -> String {
+        let FieldOptions { cols, rows, .. } = self.options;
+
+        (0..rows)
+            .map(|y| {
+                let mut row: String = (0..cols)
+                    .map(|x| {
+                        let cell = self.cells[x][y];
+
+                        match (cell.kind, cell.state) {
+                            _ if self.options.is_void(x, y) => '.',
+                            (CellKind::Mined(_), _) => '*',
+                            (CellKind::Num(n), CellState::Revealed) => (b'0' + n as u8) as char,
+                            _ => '#',
+                        }
+                    })
+                    .collect();
+
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+
+    // reveals every safe cell, then hides them in random order while the rest
+    // still prove every mine; returns false when even all of them don't
+    fn hide_givens(&mut self, max_rule: Rule, rng: &mut impl Rng) -> bool {
+        let mut givens: Vec<_> = self
+            .get_coords_all()
+            .filter(|&(x, y)| matches!(self.cells[x][y].kind, CellKind::Num(_)))
+            .collect();
+
+        for &(x, y) in &givens {
+            self.cells[x][y].state = CellState::Revealed;
+        }
+
+        if self.solve_puzzle(max_rule).is_none() {
+            return false;
+        }
+
+        givens.shuffle(rng);
+
+        for (x, y) in givens {
+            self.cells[x][y].state = CellState::Hidden;
+
+            if self.solve_puzzle(max_rule).is_none() {
+                self.cells[x][y].state = CellState::Revealed;
+            }
+        }
+
+        true
+    }
+
+    // returns the deepest rule needed when the givens prove every cell
+    // without going beyond the rule allowed
+    fn solve_puzzle(&self, max_rule: Rule) -> Option<u8> {
+        let mut solver = Solver::new(self);
+        let mut level = 0;
+
+        loop {
+            let deductions = solver.deduce();
+
+            let Some(rule) = deductions.iter().map(|d| d.rule as u8).max() else {
+                break;
+            };
+
+            if rule > max_rule as u8 {
+                break;
+            }
+
+            level = level.max(rule);
+            solver.apply(&deductions);
+        }
+
+        solver.is_solved().then_some(level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(difficulty: Difficulty) -> FieldOptions {
+        FieldOptions {
+            cols: 9,
+            rows: 9,
+            mines: 10,
+            difficulty,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn scores_are_rejected() {
+        assert!(MineField::generate_puzzle(options(Difficulty::Score(10, 20))).is_err());
+    }
+
+    #[test]
+    fn other_difficulties_are_generated() {
+        for difficulty in [Difficulty::Any, Difficulty::EasyLogic] {
+            let Ok(field) = MineField::generate_puzzle(options(difficulty)) else {
+                panic!("no puzzle generated");
+            };
+
+            assert_eq!(field.options().mines, 10);
+        }
+    }
+}
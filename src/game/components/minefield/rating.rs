@@ -0,0 +1,128 @@
+use super::{
+    solver::{Knowledge, Solver},
+    MineField,
+};
+
+// how much reasoning a board needs when played from its first click
+#[derive(Default, Clone, Copy)]
+pub struct Rating {
+    // safe cells that had to be guessed as nothing could be proved
+    pub guesses: u32,
+    // deepest solver rule used: 1 for single numbers, 2 for subsets
+    pub level: u8,
+    // most unknown cells bordering the revealed ones at once
+    pub frontier: u32,
+    // clicks needed to open the board without flags
+    pub bbbv: u32,
+    // connected groups of zeros
+    pub openings: u32,
+    pub score: u32,
+}
+
+impl Rating {
+    // 3BV weighted by the rule level, each guess counts heavily
+    // and openings make the board easier
+    fn compute_score(&mut self) {
+        let level = self.level.max(1) as u32;
+        let score = self.bbbv * (level + 1) / 2 + self.guesses * 40 + self.frontier / 2;
+
+        self.score = score.saturating_sub(self.openings * 2);
+    }
+}
+
+impl MineField {
+    // plays the board with the solver starting from the given cell,
+    // guessing a safe cell next to the revealed ones whenever it gets stuck
+    pub fn rate(&self, init_x: usize, init_y: usize) -> Rating {
+        let mut rating = Rating::default();
+        let (bbbv, openings) = self.count_bbbv();
+
+        rating.bbbv = bbbv;
+        rating.openings = openings;
+
+        let mut solver = Solver::unrevealed(self);
+        solver.reveal(init_x, init_y);
+
+        loop {
+            let frontier = solver.get_frontier();
+            rating.frontier = rating.frontier.max(frontier.len() as u32);
+
+            let deductions = solver.deduce();
+
+            if let Some(level) = deductions.iter().map(|d| d.rule as u8).max() {
+                rating.level = rating.level.max(level);
+                solver.apply(&deductions);
+                continue;
+            }
+
+            if solver.is_solved() {
+                break;
+            }
+
+            let is_safe_unknown = |&(x, y): &(usize, usize)| {
+                let cell = unsafe { self.get_cell_unchecked(x, y) };
+                !cell.is_mined() && solver.knowledge(x, y) == Knowledge::Unknown
+            };
+
+            let guess = frontier
+                .into_iter()
+                .find(is_safe_unknown)
+                .or_else(|| self.get_coords_all().find(is_safe_unknown));
+
+            match guess {
+                Some((x, y)) => {
+                    rating.guesses += 1;
+                    solver.reveal(x, y);
+                }
+                None => break,
+            }
+        }
+
+        rating.compute_score();
+        rating
+    }
+
+    // returns the 3BV and the number of openings
+    fn count_bbbv(&self) -> (u32, u32) {
+        let cols = self.options.total_cols();
+        let mut covered = vec![vec![false; self.options.rows]; cols];
+        let mut openings = 0;
+
+        let is_zero =
+            |x: usize, y: usize| unsafe { self.get_cell_unchecked(x, y) }.kind().to_int() == 0;
+
+        for (x, y) in self.get_coords_all() {
+            if covered[x][y] || !is_zero(x, y) {
+                continue;
+            }
+
+            // one click opens the zeros and everything around them
+            openings += 1;
+            covered[x][y] = true;
+
+            let mut stack = vec![(x, y)];
+
+            while let Some((x, y)) = stack.pop() {
+                for (x, y) in self.get_coords_around(x, y) {
+                    if covered[x][y] {
+                        continue;
+                    }
+
+                    covered[x][y] = true;
+
+                    if is_zero(x, y) {
+                        stack.push((x, y));
+                    }
+                }
+            }
+        }
+
+        let singles = self
+            .get_coords_all()
+            .filter(|&(x, y)| !covered[x][y])
+            .filter(|&(x, y)| !unsafe { self.get_cell_unchecked(x, y) }.is_mined())
+            .count() as u32;
+
+        (openings + singles, openings)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::DynResult;
+use sdl2::{image::LoadSurface, pixels::PixelFormatEnum, surface::Surface};
+use std::{fs, path::Path};
+
+// void positions of a board up to 30x30, one bit per cell;
+// the default shape is a full rectangle
+#[derive(Default, Clone, Copy, PartialEq)]
+pub struct BoardShape([u32; BoardShape::SIZE]);
+
+impl BoardShape {
+    pub const SIZE: usize = 30;
+
+    // plain-text silhouette, '.' and spaces mark void cells, anything else a usable one;
+    // returns the shape with its width and height
+    pub fn from_rows<'a>(
+        rows: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(Self, usize, usize), String> {
+        let rows: Vec<_> = rows.into_iter().map(str::trim_end).collect();
+        let height = rows.len();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        if width > Self::SIZE || height > Self::SIZE {
+            return Err(format!("shape is larger than {0}x{0}", Self::SIZE));
+        }
+
+        // everything outside the silhouette is void as well
+        let mut shape = Self([u32::MAX; Self::SIZE]);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, cell) in row.chars().enumerate() {
+                if cell != '.' && cell != ' ' {
+                    shape.0[y] &= !(1 << x);
+                }
+            }
+        }
+
+        if shape.count_void(Self::SIZE, Self::SIZE) == Self::SIZE * Self::SIZE {
+            return Err(String::from("shape has no usable cells"));
+        }
+
+        Ok((shape, width, height))
+    }
+
+    // a .png file is read pixel by pixel, dark opaque pixels are usable cells;
+    // anything else is read as plain text
+    pub fn load(path: impl AsRef<Path>) -> DynResult<(Self, usize, usize)> {
+        let path = path.as_ref();
+
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+        {
+            return Self::load_png(path);
+        }
+
+        let text = fs::read_to_string(path)?;
+
+        Ok(Self::from_rows(text.trim_end().lines())?)
+    }
+
+    pub fn is_void(&self, x: usize, y: usize) -> bool {
+        x < Self::SIZE && y < Self::SIZE && self.0[y] >> x & 1 == 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&row| row == 0)
+    }
+
+    pub fn count_void(&self, cols: usize, rows: usize) -> usize {
+        let mask = (1 << cols.min(Self::SIZE)) - 1;
+
+        self.0
+            .iter()
+            .take(rows)
+            .map(|row| (row & mask).count_ones() as usize)
+            .sum()
+    }
+
+    pub fn to_rows(&self, cols: usize, rows: usize) -> impl Iterator<Item = String> + '_ {
+        (0..rows).map(move |y| {
+            (0..cols)
+                .map(|x| if self.is_void(x, y) { '.' } else { '#' })
+                .collect()
+        })
+    }
+
+    fn load_png(path: &Path) -> DynResult<(Self, usize, usize)> {
+        let surface = Surface::from_file(path)?.convert_format(PixelFormatEnum::RGBA32)?;
+        let (width, height) = (surface.width() as usize, surface.height() as usize);
+        let pitch = surface.pitch() as usize;
+
+        let rows = surface.with_lock(|pixels| {
+            (0..height)
+                .map(|y| {
+                    (0..width)
+                        .map(|x| {
+                            let i = y * pitch + x * 4;
+                            let [r, g, b, a] =
+                                [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]];
+                            let dark = (r as u32 + g as u32 + b as u32) < 384;
+
+                            if a >= 128 && dark {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Ok(Self::from_rows(rows.iter().map(String::as_str))?)
+    }
+}
@@ -0,0 +1,545 @@
+use super::{
+    CellKind, Difficulty, FieldOptions, FirstClick, LayoutPlacement, Luck, MineField,
+    Neighbourhood, Placement, SeededPlacement, Tiling, Topology,
+};
+
+// a board that can be passed around as a short code, holding either the seed
+// the mines were placed with or the mines themselves
+pub struct SharedBoard {
+    pub options: FieldOptions,
+    pub first_click: (usize, usize),
+    pub seed: Option<u64>,
+    pub mines: Vec<(usize, usize)>,
+}
+
+impl SharedBoard {
+    const VERSION: u8 = 1;
+    const LAYOUT: u8 = 0;
+    const SEED: u8 = 1;
+    const HEADER_LEN: usize = 8;
+
+    // the mines as they lie on the field now
+    pub fn from_field(field: &MineField, first_click: (usize, usize)) -> Self {
+        let mines = field
+            .get_coords_all()
+            .flat_map(|(x, y)| {
+                let count = match field.cells[x][y].kind {
+                    CellKind::Mined(n) => n as usize,
+                    CellKind::Num(_) => 0,
+                };
+
+                std::iter::repeat_n((x, y), count)
+            })
+            .collect();
+
+        Self {
+            options: field.options(),
+            first_click,
+            seed: None,
+            mines,
+        }
+    }
+
+    // the first board of a seeded placement, opened at the given cell;
+    // difficulty and luck are left out, so the mines stay where the seed puts them
+    pub fn from_seed(options: FieldOptions, seed: u64, first_click: (usize, usize)) -> Self {
+        let mut field = MineField::new(FieldOptions {
+            placement: Placement::Seeded(seed),
+            difficulty: Difficulty::Any,
+            luck: Luck::Fixed,
+            ..options
+        });
+
+        let (x, y) = first_click;
+        field.place_mines(x, y, &mut SeededPlacement::new(seed));
+
+        Self {
+            seed: Some(seed),
+            ..Self::from_field(&field, first_click)
+        }
+    }
+
+    // places the mines of the board instead of random ones
+    pub fn placement(&self) -> LayoutPlacement {
+        LayoutPlacement::from_mines(self.mines.clone())
+    }
+
+    // version and kind, columns, rows, layers with mines per cell and tiling,
+    // topology with neighbourhood and first click policy, the first click cell,
+    // then the seed and mine count or two bits per cell (one for single mines),
+    // and a CRC-16 of all that; written in URL-safe base64
+    pub fn encode(&self) -> Result<String, String> {
+        let options = self.options;
+
+        if !options.shape.is_empty() {
+            return Err(String::from("boards with void cells can't be shared"));
+        }
+
+        let neighbourhood = match options.neighbourhood {
+            Neighbourhood::Adjacent => 0,
+            Neighbourhood::Orthogonal => 1,
+            Neighbourhood::Knight => 2,
+            Neighbourhood::Radius2 => 3,
+            Neighbourhood::Custom(_) => {
+                return Err(String::from(
+                    "boards with custom neighbours can't be shared",
+                ))
+            }
+        };
+
+        let tiling = match options.tiling {
+            Tiling::Square => 0,
+            Tiling::Hex => 1,
+            Tiling::Triangle => 2,
+        };
+
+        let first_click = match options.first_click {
+            FirstClick::Cell => 0,
+            FirstClick::Opening => 1,
+            FirstClick::Radius(radius @ 0..=3) => 2 + radius,
+            FirstClick::Radius(radius) => {
+                return Err(format!(
+                    "a first click radius of {radius} can't be shared, 3 is the most"
+                ))
+            }
+        };
+
+        let kind = match self.seed {
+            Some(_) => Self::SEED,
+            None => Self::LAYOUT,
+        };
+
+        let (x, y) = self.first_click;
+
+        let mut bytes = vec![
+            Self::VERSION << 4 | kind,
+            options.cols as u8,
+            options.rows as u8,
+            (options.layers as u8) << 4 | options.mines_per_cell << 2 | tiling,
+            ((options.topology == Topology::Torus) as u8) << 5 | neighbourhood << 3 | first_click,
+        ];
+
+        bytes.extend((x as u16).to_be_bytes());
+        bytes.push(y as u8);
+
+        match self.seed {
+            Some(seed) => {
+                bytes.extend(seed.to_be_bytes());
+                bytes.extend((options.mines as u16).to_be_bytes());
+            }
+            None => bytes.extend(pack_mines(self)),
+        }
+
+        bytes.extend(crc16(&bytes).to_be_bytes());
+
+        Ok(encode_base64(&bytes))
+    }
+
+    pub fn decode(code: &str) -> Result<Self, String> {
+        let bytes = decode_base64(code.trim())?;
+
+        let Some((body, checksum)) = bytes.split_last_chunk::<2>() else {
+            return Err(String::from("the code is too short"));
+        };
+
+        if crc16(body) != u16::from_be_bytes(*checksum) {
+            return Err(String::from(
+                "the checksum doesn't match, the code is mistyped or cut",
+            ));
+        }
+
+        let Some((header, payload)) = body.split_first_chunk::<{ Self::HEADER_LEN }>() else {
+            return Err(String::from("the code is too short"));
+        };
+
+        let [kind, cols, rows, sizes, rules, x_high, x_low, y] = *header;
+
+        if kind >> 4 != Self::VERSION {
+            return Err(format!("version {} codes aren't known", kind >> 4));
+        }
+
+        let tiling = match sizes & 0b11 {
+            0 => Tiling::Square,
+            1 => Tiling::Hex,
+            2 => Tiling::Triangle,
+            _ => return Err(String::from("unknown tiling")),
+        };
+
+        let neighbourhood = match rules >> 3 & 0b11 {
+            0 => Neighbourhood::Adjacent,
+            1 => Neighbourhood::Orthogonal,
+            2 => Neighbourhood::Knight,
+            _ => Neighbourhood::Radius2,
+        };
+
+        let first_click = match rules & 0b111 {
+            0 => FirstClick::Cell,
+            1 => FirstClick::Opening,
+            radius @ 2..=5 => FirstClick::Radius(radius - 2),
+            _ => return Err(String::from("unknown first click policy")),
+        };
+
+        let options = FieldOptions {
+            cols: cols as usize,
+            rows: rows as usize,
+            layers: (sizes >> 4) as usize,
+            mines_per_cell: sizes >> 2 & 0b11,
+            tiling,
+            topology: match rules >> 5 & 1 {
+                0 => Topology::Plane,
+                _ => Topology::Torus,
+            },
+            neighbourhood,
+            first_click,
+            ..Default::default()
+        };
+
+        // the field would clamp anything else into another board
+        let field = MineField::new(options);
+        let clamped = field.options();
+
+        let size_of = |o: FieldOptions| (o.cols, o.rows, o.layers, o.mines_per_cell);
+
+        if size_of(clamped) != size_of(options) {
+            return Err(format!(
+                "a {}x{} board with {} layers and {} mines per cell can't be played",
+                cols, rows, options.layers, options.mines_per_cell
+            ));
+        }
+
+        let first_click = (u16::from_be_bytes([x_high, x_low]) as usize, y as usize);
+
+        if first_click.0 >= clamped.total_cols() || first_click.1 >= clamped.rows {
+            return Err(format!(
+                "the first click at ({},{}) is off the board",
+                first_click.0, first_click.1
+            ));
+        }
+
+        match kind & 0b1111 {
+            Self::SEED => {
+                let [s0, s1, s2, s3, s4, s5, s6, s7, m0, m1] = *payload else {
+                    return Err(format!(
+                        "a seed takes 10 bytes, the code has {}",
+                        payload.len()
+                    ));
+                };
+
+                let seed = u64::from_be_bytes([s0, s1, s2, s3, s4, s5, s6, s7]);
+                let mines = u16::from_be_bytes([m0, m1]) as usize;
+
+                Ok(Self::from_seed(
+                    FieldOptions { mines, ..clamped },
+                    seed,
+                    first_click,
+                ))
+            }
+            Self::LAYOUT => {
+                let mines = unpack_mines(&field, payload)?;
+
+                Ok(Self {
+                    options: FieldOptions {
+                        mines: mines.len(),
+                        ..clamped
+                    },
+                    first_click,
+                    seed: None,
+                    mines,
+                })
+            }
+            kind => Err(format!("unknown kind of code {kind}")),
+        }
+    }
+}
+
+fn bits_per_cell(options: FieldOptions) -> usize {
+    match options.mines_per_cell {
+        1 => 1,
+        _ => 2,
+    }
+}
+
+fn pack_mines(board: &SharedBoard) -> Vec<u8> {
+    let options = board.options;
+    let bits = bits_per_cell(options);
+    let cells = options.total_cols() * options.rows;
+
+    let mut counts = vec![0u8; cells];
+
+    for &(x, y) in &board.mines {
+        counts[x * options.rows + y] += 1;
+    }
+
+    let mut bytes = vec![0u8; (cells * bits).div_ceil(8)];
+
+    for (index, &count) in counts.iter().enumerate() {
+        let bit = index * bits;
+        bytes[bit / 8] |= count << (8 - bits - bit % 8);
+    }
+
+    bytes
+}
+
+fn unpack_mines(field: &MineField, payload: &[u8]) -> Result<Vec<(usize, usize)>, String> {
+    let options = field.options();
+    let bits = bits_per_cell(options);
+    let cells = options.total_cols() * options.rows;
+    let expected = (cells * bits).div_ceil(8);
+
+    if payload.len() != expected {
+        return Err(format!(
+            "the layout takes {} bytes, the code has {}",
+            expected,
+            payload.len()
+        ));
+    }
+
+    let mut mines = Vec::new();
+
+    for index in 0..cells {
+        let bit = index * bits;
+        let count = payload[bit / 8] >> (8 - bits - bit % 8) & ((1 << bits) - 1);
+        let (x, y) = (index / options.rows, index % options.rows);
+
+        if count > options.mines_per_cell {
+            return Err(format!("({x},{y}) holds {count} mines"));
+        }
+
+        mines.extend(std::iter::repeat_n((x, y), count as usize));
+    }
+
+    Ok(mines)
+}
+
+// CRC-16/CCITT-FALSE
+fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc = 0xffff_u16;
+
+    for &byte in bytes {
+        crc ^= (byte as u16) << 8;
+
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                crc << 1 ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// without padding
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut text = String::new();
+
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (i, &byte)| {
+            value | (byte as u32) << (16 - 8 * i)
+        });
+
+        for i in 0..=chunk.len() {
+            text.push(BASE64[(value >> (18 - 6 * i) & 63) as usize] as char);
+        }
+    }
+
+    text
+}
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    let (mut value, mut bits) = (0u32, 0);
+
+    for (index, c) in text.chars().enumerate() {
+        let Some(digit) = BASE64.iter().position(|&d| d as char == c) else {
+            return Err(format!(
+                "character {}: '{}' isn't part of a share code",
+                index + 1,
+                c
+            ));
+        };
+
+        value = value << 6 | digit as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((value >> bits) as u8);
+            value &= (1 << bits) - 1;
+        }
+    }
+
+    // the last character only pads its byte, so a typo there must not go unseen
+    if bits >= 6 || value != 0 {
+        return Err(String::from("the share code ends in the middle of a byte"));
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::OffsetMask;
+    use super::*;
+
+    fn options() -> FieldOptions {
+        FieldOptions {
+            cols: 16,
+            rows: 12,
+            layers: 2,
+            mines: 40,
+            mines_per_cell: 2,
+            first_click: FirstClick::Radius(2),
+            topology: Topology::Torus,
+            neighbourhood: Neighbourhood::Knight,
+            ..Default::default()
+        }
+    }
+
+    fn sorted(mut mines: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        mines.sort();
+        mines
+    }
+
+    fn assert_same(a: &SharedBoard, b: &SharedBoard) {
+        let size_of = |o: FieldOptions| (o.cols, o.rows, o.layers, o.mines, o.mines_per_cell);
+
+        assert_eq!(size_of(a.options), size_of(b.options));
+        assert!(a.options.first_click == b.options.first_click);
+        assert!(a.options.topology == b.options.topology);
+        assert!(a.options.neighbourhood == b.options.neighbourhood);
+        assert_eq!(a.first_click, b.first_click);
+        assert_eq!(a.seed, b.seed);
+        assert_eq!(sorted(a.mines.clone()), sorted(b.mines.clone()));
+    }
+
+    // the bytes of a code with the checksum made anew
+    fn reencode(code: &str, change: impl Fn(&mut Vec<u8>)) -> String {
+        let Ok(mut bytes) = decode_base64(code) else {
+            panic!("the code doesn't decode");
+        };
+
+        bytes.truncate(bytes.len() - 2);
+        change(&mut bytes);
+        bytes.extend(crc16(&bytes).to_be_bytes());
+
+        encode_base64(&bytes)
+    }
+
+    #[test]
+    fn seed_codes_round_trip() {
+        for seed in [0, 1, 0xdead_beef, u64::MAX] {
+            let board = SharedBoard::from_seed(options(), seed, (20, 5));
+            let code = board.encode().unwrap_or_default();
+
+            match SharedBoard::decode(&code) {
+                Ok(decoded) => assert_same(&board, &decoded),
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn layout_codes_round_trip() {
+        for seed in 0..10 {
+            let mut field = MineField::new(FieldOptions {
+                mines_per_cell: (seed % 2 + 1) as u8,
+                ..options()
+            });
+            field.place_mines(3, 7, &mut SeededPlacement::new(seed));
+
+            let board = SharedBoard::from_field(&field, (3, 7));
+            let code = board.encode().unwrap_or_default();
+
+            match SharedBoard::decode(&code) {
+                Ok(decoded) => assert_same(&board, &decoded),
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn checksums_catch_typos() {
+        let code = SharedBoard::from_seed(options(), 42, (0, 0))
+            .encode()
+            .unwrap_or_default();
+
+        for index in 0..code.len() {
+            let mut typo = code.clone().into_bytes();
+            typo[index] = if typo[index] == b'A' { b'B' } else { b'A' };
+
+            let typo = String::from_utf8(typo).unwrap_or_default();
+            assert!(SharedBoard::decode(&typo).is_err());
+        }
+
+        assert!(SharedBoard::decode(&code[..code.len() - 3]).is_err());
+        assert!(SharedBoard::decode("").is_err());
+        assert!(SharedBoard::decode("not a code!").is_err());
+    }
+
+    #[test]
+    fn padding_bits_are_checked() {
+        for bytes in [&[0xff][..], &[0xff, 0xff], &[0xff, 0xff, 0xff]] {
+            let text = encode_base64(bytes);
+            assert_eq!(decode_base64(&text), Ok(bytes.to_vec()));
+
+            // the next character differs only in the lowest bit
+            let mut typo = text.into_bytes();
+            let last = typo.len() - 1;
+            typo[last] =
+                BASE64[(BASE64.iter().position(|&d| d == typo[last]).unwrap_or(0) + 1) % 64];
+
+            let typo = String::from_utf8(typo).unwrap_or_default();
+            assert_eq!(decode_base64(&typo).is_err(), bytes.len() % 3 != 0);
+        }
+
+        assert!(decode_base64("A").is_err());
+    }
+
+    #[test]
+    fn payloads_of_the_wrong_length_fail() {
+        let seeded = SharedBoard::from_seed(options(), 42, (0, 0))
+            .encode()
+            .unwrap_or_default();
+
+        let mut field = MineField::new(options());
+        field.place_mines(0, 0, &mut SeededPlacement::new(42));
+
+        let layout = SharedBoard::from_field(&field, (0, 0))
+            .encode()
+            .unwrap_or_default();
+
+        for code in [seeded, layout] {
+            assert!(SharedBoard::decode(&code).is_ok());
+            assert!(SharedBoard::decode(&reencode(&code, |bytes| bytes.push(0))).is_err());
+            assert!(SharedBoard::decode(&reencode(&code, |bytes| {
+                bytes.pop();
+            }))
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn unshareable_options_fail() {
+        let board = |options| SharedBoard::from_seed(options, 1, (0, 0));
+
+        let wide_click = FieldOptions {
+            first_click: FirstClick::Radius(4),
+            ..options()
+        };
+        let custom = FieldOptions {
+            neighbourhood: Neighbourhood::Custom(OffsetMask::from_offsets(&[(1, 0)])),
+            ..options()
+        };
+
+        // the field clamps the radius, so it's set on the board afterwards
+        let mut wide = board(options());
+        wide.options = wide_click;
+
+        assert!(wide.encode().is_err());
+        assert!(board(custom).encode().is_err());
+    }
+}
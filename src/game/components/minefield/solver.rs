@@ -0,0 +1,493 @@
+use super::{CellKind, CellState, FieldOptions, MineField};
+use rand::seq::SliceRandom;
+use std::{collections::HashMap, ops::RangeInclusive};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Knowledge {
+    Unknown,
+    Safe,
+    // holds as many mines as a cell can
+    Mined,
+}
+
+// deduces safe and mined cells from the numbers of the safe cells it knows about,
+// the hidden layout of the field is never looked at
+pub struct Solver<'a> {
+    field: &'a MineField,
+    known: Vec<Vec<Knowledge>>,
+}
+
+// unknown cells around a safe one and how many mines are still missing among them
+struct Constraint {
+    unknown: Vec<(usize, usize)>,
+    mines: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Rule {
+    // a number sees exactly as many unknown cells as mines left, or none
+    Single = 1,
+    // the unknown cells of one number are all seen by another one
+    Subset = 2,
+}
+
+pub struct Deduction {
+    pub coords: (usize, usize),
+    pub knowledge: Knowledge,
+    pub rule: Rule,
+    // the numbered cells it follows from, the smaller subset first
+    pub sources: Vec<(usize, usize)>,
+}
+
+impl<'a> Solver<'a> {
+    // starts from the cells already revealed on the field
+    pub fn new(field: &'a MineField) -> Self {
+        let mut solver = Self::unrevealed(field);
+
+        for (x, y) in field.get_coords_all() {
+            let cell = unsafe { field.get_cell_unchecked(x, y) };
+
+            if cell.state() == CellState::Revealed && !cell.is_mined() {
+                solver.known[x][y] = Knowledge::Safe;
+            }
+        }
+
+        solver
+    }
+
+    // starts from scratch, whatever has been revealed on the field
+    pub fn unrevealed(field: &'a MineField) -> Self {
+        let FieldOptions { rows, .. } = field.options();
+        let cols = field.options().total_cols();
+
+        let known = vec![vec![Knowledge::Unknown; rows]; cols];
+
+        Self { field, known }
+    }
+
+    pub fn knowledge(&self, x: usize, y: usize) -> Knowledge {
+        self.known[x][y]
+    }
+
+    // the number of a revealed cell becomes known, the cell must not be mined
+    pub fn reveal(&mut self, x: usize, y: usize) {
+        self.known[x][y] = Knowledge::Safe;
+    }
+
+    pub fn apply(&mut self, deductions: &[Deduction]) {
+        for deduction in deductions {
+            let (x, y) = deduction.coords;
+            self.known[x][y] = deduction.knowledge;
+        }
+    }
+
+    // applies one round of deductions, returns false when nothing new was found
+    pub fn step(&mut self) -> bool {
+        let deductions = self.deduce();
+        self.apply(&deductions);
+
+        !deductions.is_empty()
+    }
+
+    // returns whether every safe cell of the field could be found
+    pub fn solve(&mut self) -> bool {
+        while self.step() {}
+
+        self.is_solved()
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.field.get_coords_all().all(|(x, y)| {
+            let cell = unsafe { self.field.get_cell_unchecked(x, y) };
+
+            cell.is_mined() || self.known[x][y] == Knowledge::Safe
+        })
+    }
+
+    // unknown cells next to known safe ones
+    pub fn get_frontier(&self) -> Vec<(usize, usize)> {
+        self.field
+            .get_coords_all()
+            .filter(|&(x, y)| self.known[x][y] == Knowledge::Unknown)
+            .filter(|&(x, y)| {
+                self.field
+                    .get_coords_around(x, y)
+                    .any(|(x, y)| self.known[x][y] == Knowledge::Safe)
+            })
+            .collect()
+    }
+
+    // the simplest rule giving anything is used alone
+    pub fn deduce(&self) -> Vec<Deduction> {
+        let per_cell = self.field.options().mines_per_cell as usize;
+
+        let constraints: HashMap<_, _> = self
+            .field
+            .get_coords_all()
+            .filter_map(|(x, y)| Some(((x, y), self.get_constraint(x, y)?)))
+            .collect();
+
+        let mut deductions: Vec<Deduction> = Vec::new();
+
+        for (&coords, a) in &constraints {
+            let sources = [coords];
+            conclude(&mut deductions, &a.unknown, a.mines, per_cell, &sources);
+        }
+
+        if !deductions.is_empty() {
+            return deductions;
+        }
+
+        for (&coords, a) in &constraints {
+            // constraints sharing all of their unknown cells with a larger one
+            // leave the difference with the remaining mines
+            for &(ux, uy) in &a.unknown {
+                for other in self.field.get_coords_around(ux, uy) {
+                    let Some(b) = constraints.get(&other) else {
+                        continue;
+                    };
+
+                    if other == coords
+                        || b.unknown.len() <= a.unknown.len()
+                        || b.mines < a.mines
+                        || !a.unknown.iter().all(|cell| b.unknown.contains(cell))
+                    {
+                        continue;
+                    }
+
+                    let rest: Vec<_> = b
+                        .unknown
+                        .iter()
+                        .copied()
+                        .filter(|cell| !a.unknown.contains(cell))
+                        .collect();
+
+                    let mines = b.mines - a.mines;
+                    let sources = [coords, other];
+                    conclude(&mut deductions, &rest, mines, per_cell, &sources);
+                }
+            }
+        }
+
+        deductions
+    }
+
+    fn get_constraint(&self, x: usize, y: usize) -> Option<Constraint> {
+        if self.known[x][y] != Knowledge::Safe {
+            return None;
+        }
+
+        let per_cell = self.field.options().mines_per_cell as usize;
+        let number = unsafe { self.field.get_cell_unchecked(x, y) }
+            .kind()
+            .to_int() as usize;
+
+        let mut unknown = Vec::new();
+        let mut mined = 0;
+
+        for (x, y) in self.field.get_coords_around(x, y) {
+            match self.known[x][y] {
+                Knowledge::Unknown => unknown.push((x, y)),
+                Knowledge::Safe => (),
+                Knowledge::Mined => mined += per_cell,
+            }
+        }
+
+        if unknown.is_empty() {
+            return None;
+        }
+
+        Some(Constraint {
+            unknown,
+            mines: number.saturating_sub(mined),
+        })
+    }
+}
+
+// all the cells are safe when no mines are left for them,
+// or mined when the mines left fill them up
+fn conclude(
+    deductions: &mut Vec<Deduction>,
+    cells: &[(usize, usize)],
+    mines: usize,
+    per_cell: usize,
+    sources: &[(usize, usize)],
+) {
+    let rule = match sources.len() {
+        1 => Rule::Single,
+        _ => Rule::Subset,
+    };
+
+    let knowledge = if mines == 0 {
+        Knowledge::Safe
+    } else if mines == cells.len() * per_cell {
+        Knowledge::Mined
+    } else {
+        return;
+    };
+
+    for &coords in cells {
+        if !deductions
+            .iter()
+            .any(|deduction| deduction.coords == coords)
+        {
+            deductions.push(Deduction {
+                coords,
+                knowledge,
+                rule,
+                sources: sources.to_vec(),
+            });
+        }
+    }
+}
+
+// searches for a layout agreeing with every revealed number where the given cell
+// is mined or not, keeping the safe cells free; returns the mined cells, one entry per mine
+pub fn find_layout(
+    field: &MineField,
+    target: (usize, usize),
+    mined: bool,
+    safe: &[(usize, usize)],
+) -> Option<Vec<(usize, usize)>> {
+    if mined && safe.contains(&target) {
+        return None;
+    }
+
+    let options = field.options();
+    let per_cell = options.mines_per_cell as usize;
+
+    let mut search = LayoutSearch {
+        per_cell,
+        values: Vec::new(),
+        links: Vec::new(),
+        constraints: Vec::new(),
+        placed: 0,
+        open: 0,
+        budget: LayoutSearch::BUDGET,
+    };
+
+    // hidden cells next to revealed numbers are searched through,
+    // the ones further away only have to take the remaining mines
+    let mut frontier = Vec::new();
+    let mut indices = HashMap::new();
+    let mut interior = Vec::new();
+
+    for (x, y) in field.get_coords_all() {
+        let cell = unsafe { field.get_cell_unchecked(x, y) };
+
+        if cell.state() != CellState::Revealed {
+            continue;
+        }
+
+        if cell.is_mined() {
+            return None;
+        }
+
+        let mut cells = Vec::new();
+
+        for coords in field.get_coords_around(x, y) {
+            let neighbour = unsafe { field.get_cell_unchecked(coords.0, coords.1) };
+
+            if neighbour.state() == CellState::Revealed {
+                continue;
+            }
+
+            let index = *indices.entry(coords).or_insert_with(|| {
+                frontier.push(coords);
+                search.links.push(Vec::new());
+                frontier.len() - 1
+            });
+
+            search.links[index].push(search.constraints.len());
+            cells.push(index);
+        }
+
+        if !cells.is_empty() {
+            search.constraints.push(LayoutConstraint {
+                mines: cell.kind().to_int() as usize,
+                assigned: 0,
+                open: cells.len(),
+            });
+        }
+    }
+
+    for (x, y) in field.get_coords_all() {
+        let cell = unsafe { field.get_cell_unchecked(x, y) };
+
+        let free = indices.contains_key(&(x, y)) || safe.contains(&(x, y));
+
+        if cell.state() != CellState::Revealed && !free {
+            interior.push((x, y));
+        }
+    }
+
+    let target_inside = interior.contains(&target);
+    let interior_min = usize::from(target_inside && mined);
+    let interior_max =
+        interior.len() * per_cell - if target_inside && !mined { per_cell } else { 0 };
+
+    // the placement may have put another number of mines than asked
+    let total: usize = field
+        .get_coords_all()
+        .map(
+            |(x, y)| match unsafe { field.get_cell_unchecked(x, y) }.kind() {
+                CellKind::Mined(n) => n as usize,
+                CellKind::Num(_) => 0,
+            },
+        )
+        .sum();
+    let frontier_max = total.checked_sub(interior_min)?;
+    let frontier_min = total.saturating_sub(interior_max);
+
+    search.values = vec![None; frontier.len()];
+    search.open = frontier.len();
+
+    for coords in safe {
+        if let Some(&index) = indices.get(coords) {
+            if !search.assign_fixed(index, 0..=0) {
+                return None;
+            }
+        }
+    }
+
+    if let Some(&index) = indices.get(&target) {
+        let range = if mined { 1..=per_cell } else { 0..=0 };
+
+        if !search.assign_fixed(index, range) {
+            return None;
+        }
+    }
+
+    if !search.run(0, frontier_min, frontier_max) {
+        return None;
+    }
+
+    let mut mines = Vec::new();
+
+    for (&coords, &value) in frontier.iter().zip(&search.values) {
+        mines.extend(std::iter::repeat_n(coords, value.unwrap_or(0)));
+    }
+
+    // the rest goes to random cells away from the numbers
+    let mut left = total - mines.len();
+
+    if target_inside && mined {
+        mines.push(target);
+        left -= 1;
+    }
+
+    let mut slots: Vec<_> = interior
+        .iter()
+        .filter(|&&coords| !(target_inside && coords == target))
+        .flat_map(|&coords| std::iter::repeat_n(coords, per_cell))
+        .collect();
+
+    if target_inside && mined {
+        slots.extend(std::iter::repeat_n(target, per_cell - 1));
+    }
+
+    slots.shuffle(&mut rand::rng());
+    mines.extend(slots.into_iter().take(left));
+
+    Some(mines)
+}
+
+struct LayoutConstraint {
+    mines: usize,
+    assigned: usize,
+    open: usize,
+}
+
+// plain backtracking over the frontier cells in a random value order
+struct LayoutSearch {
+    per_cell: usize,
+    values: Vec<Option<usize>>,
+    links: Vec<Vec<usize>>,
+    constraints: Vec<LayoutConstraint>,
+    // mines assigned so far and cells still without a value
+    placed: usize,
+    open: usize,
+    budget: usize,
+}
+
+impl LayoutSearch {
+    // nodes visited before giving up on a layout
+    const BUDGET: usize = 200_000;
+
+    fn assign_fixed(&mut self, index: usize, range: RangeInclusive<usize>) -> bool {
+        let mut choices: Vec<_> = range.collect();
+        choices.shuffle(&mut rand::rng());
+
+        choices
+            .into_iter()
+            .any(|value| self.try_assign(index, value))
+    }
+
+    fn run(&mut self, index: usize, min: usize, max: usize) -> bool {
+        if self.placed > max || self.placed + self.open * self.per_cell < min {
+            return false;
+        }
+
+        let Some(next) = (index..self.values.len()).find(|&i| self.values[i].is_none()) else {
+            return true;
+        };
+
+        if self.budget == 0 {
+            return false;
+        }
+
+        self.budget -= 1;
+
+        let mut choices: Vec<_> = (0..=self.per_cell).collect();
+        choices.shuffle(&mut rand::rng());
+
+        for value in choices {
+            if !self.try_assign(next, value) {
+                continue;
+            }
+
+            if self.run(next + 1, min, max) {
+                return true;
+            }
+
+            self.unassign(next);
+        }
+
+        false
+    }
+
+    // keeps the value when no constraint is broken by it
+    fn try_assign(&mut self, index: usize, value: usize) -> bool {
+        let fits = self.links[index].iter().all(|&c| {
+            let constraint = &self.constraints[c];
+            let assigned = constraint.assigned + value;
+            let open = constraint.open - 1;
+
+            assigned <= constraint.mines && assigned + open * self.per_cell >= constraint.mines
+        });
+
+        if fits {
+            for &c in &self.links[index] {
+                self.constraints[c].assigned += value;
+                self.constraints[c].open -= 1;
+            }
+
+            self.values[index] = Some(value);
+            self.placed += value;
+            self.open -= 1;
+        }
+
+        fits
+    }
+
+    fn unassign(&mut self, index: usize) {
+        let value = self.values[index].take().unwrap_or(0);
+        self.placed -= value;
+        self.open += 1;
+
+        for &c in &self.links[index] {
+            self.constraints[c].assigned -= value;
+            self.constraints[c].open += 1;
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use super::minefield::{MineField, Puzzle};
+use crate::DynResult;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Default, Clone, Copy)]
+pub struct PuzzleStats {
+    pub attempts: u32,
+    pub solves: u32,
+    pub best_millis: Option<u64>,
+}
+
+// a pack being played through; the stats are kept in a `.progress` file
+// next to the pack, one line per puzzle: attempts, solves, best time and name
+pub struct PuzzleSession {
+    names: Vec<String>,
+    fields: Vec<MineField>,
+    stats: Vec<PuzzleStats>,
+    current: usize,
+    progress_path: PathBuf,
+}
+
+impl PuzzleSession {
+    // every puzzle is checked up front, play starts at the first unsolved one
+    pub fn load(path: impl AsRef<Path>) -> DynResult<Self> {
+        let path = path.as_ref();
+        let puzzles = Puzzle::parse_pack(&fs::read_to_string(path)?);
+
+        if puzzles.is_empty() {
+            return Err("the pack has no puzzles".into());
+        }
+
+        let cols = puzzles.iter().map(Puzzle::width).max().unwrap_or(0);
+        let rows = puzzles.iter().map(Puzzle::height).max().unwrap_or(0);
+
+        let fields = puzzles
+            .iter()
+            .map(|puzzle| MineField::from_puzzle(puzzle, cols, rows))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let names: Vec<_> = puzzles.into_iter().map(|puzzle| puzzle.name).collect();
+
+        let mut progress_path = path.as_os_str().to_owned();
+        progress_path.push(".progress");
+        let progress_path = PathBuf::from(progress_path);
+
+        let stats = match fs::read_to_string(&progress_path) {
+            Ok(text) => read_progress(&text, &names),
+            Err(_) => vec![PuzzleStats::default(); names.len()],
+        };
+
+        let current = stats
+            .iter()
+            .position(|stats| stats.solves == 0)
+            .unwrap_or(0);
+
+        Ok(Self {
+            names,
+            fields,
+            stats,
+            current,
+            progress_path,
+        })
+    }
+
+    pub fn field(&self) -> MineField {
+        self.fields[self.current].clone()
+    }
+
+    // e.g. "puzzle 3/12 - crossing - 2 solved"
+    pub fn describe(&self) -> String {
+        let solved = self.stats.iter().filter(|stats| stats.solves > 0).count();
+
+        format!(
+            "puzzle {}/{} - {} - {} solved",
+            self.current + 1,
+            self.fields.len(),
+            self.names[self.current],
+            solved
+        )
+    }
+
+    pub fn skip(&mut self) {
+        self.current = (self.current + 1) % self.fields.len();
+    }
+
+    // a solved puzzle moves play on to the next one;
+    // returns the updated stats of the puzzle played
+    pub fn record(&mut self, won: bool, millis: u64) -> DynResult<PuzzleStats> {
+        let stats = &mut self.stats[self.current];
+        stats.attempts += 1;
+
+        if won {
+            stats.solves += 1;
+            stats.best_millis = Some(stats.best_millis.map_or(millis, |best| best.min(millis)));
+        }
+
+        let stats = *stats;
+
+        let text: String = self
+            .names
+            .iter()
+            .zip(&self.stats)
+            .map(|(name, stats)| {
+                let best = stats
+                    .best_millis
+                    .map_or(String::from("-"), |best| best.to_string());
+
+                format!("{} {} {} {}\n", stats.attempts, stats.solves, best, name)
+            })
+            .collect();
+
+        fs::write(&self.progress_path, text)?;
+
+        if won {
+            self.skip();
+        }
+
+        Ok(stats)
+    }
+}
+
+// lines with unknown names or in a wrong format are left out
+fn read_progress(text: &str, names: &[String]) -> Vec<PuzzleStats> {
+    let mut stats = vec![PuzzleStats::default(); names.len()];
+
+    for line in text.lines() {
+        let mut parts = line.splitn(4, ' ');
+
+        let (Some(attempts), Some(solves), Some(best), Some(name)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+
+        let Some(index) = names.iter().position(|known| known == name) else {
+            continue;
+        };
+
+        let (Ok(attempts), Ok(solves)) = (attempts.parse(), solves.parse()) else {
+            continue;
+        };
+
+        stats[index] = PuzzleStats {
+            attempts,
+            solves,
+            best_millis: best.parse().ok(),
+        };
+    }
+
+    stats
+}
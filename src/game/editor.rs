@@ -0,0 +1,210 @@
+use super::{
+    components::minefield::{BoardFile, FieldOptions, MineField},
+    rendering::Renderer,
+    settings::Settings,
+};
+use crate::{Context, DynResult, SdlResult};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// left click adds a mine to a cell and right click empties it, the numbers
+// follow every change; the rest is done with keys, see lib.rs
+pub struct EditorHandler {
+    field: MineField,
+    renderer: Renderer,
+    settings: Settings,
+    // the board file, saved puzzles go next to it
+    path: PathBuf,
+    // mines asked for by the options, the random fill stops there
+    fill_target: usize,
+    play_requested: bool,
+    is_active: bool,
+}
+
+// public methods
+impl EditorHandler {
+    // `fill_target` is the mine count the random fill stops at
+    pub fn init(
+        context: &Context,
+        field: MineField,
+        fill_target: usize,
+        settings: Settings,
+        path: impl Into<PathBuf>,
+    ) -> DynResult<Self> {
+        let renderer = Renderer::init(context, field.options(), settings)?;
+
+        let mut handler = Self {
+            field,
+            renderer,
+            settings,
+            path: path.into(),
+            fill_target,
+            play_requested: false,
+            is_active: true,
+        };
+
+        handler.draw_field();
+
+        Ok(handler)
+    }
+
+    // the board file when it exists, a blank board otherwise;
+    // .mbf files are read as MBF, the rest as layouts
+    pub fn load_board(options: FieldOptions, path: impl AsRef<Path>) -> DynResult<MineField> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Ok(MineField::from_layout(options, "")?);
+        }
+
+        if BoardFile::is_mbf(path) {
+            let board = BoardFile::load(path)?;
+            return Ok(MineField::from_board_file(&board, options)?);
+        }
+
+        Ok(MineField::from_layout(options, &fs::read_to_string(path)?)?)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn is_play_requested(&self) -> bool {
+        self.play_requested
+    }
+
+    // closes the window
+    pub fn into_field(self) -> MineField {
+        self.field
+    }
+
+    pub fn left_click(&mut self, x: i32, y: i32) {
+        if self.renderer.button_contains(x, y) {
+            self.play();
+            return;
+        }
+
+        if let Some((x, y)) = self.renderer.get_cell_pos(x, y) {
+            self.field.cycle_mine(x, y);
+            self.draw_field();
+        }
+    }
+
+    pub fn right_click(&mut self, x: i32, y: i32) {
+        if let Some((x, y)) = self.renderer.get_cell_pos(x, y) {
+            self.field.remove_mine(x, y);
+            self.draw_field();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.field.remove_all_mines();
+        self.draw_field();
+    }
+
+    pub fn fill(&mut self) {
+        self.field.fill_mines(self.fill_target);
+        self.draw_field();
+    }
+
+    pub fn mirror(&mut self) {
+        self.field.mirror();
+        self.draw_field();
+    }
+
+    // the window is made anew for the new size
+    pub fn resize(&mut self, context: &Context, cols: isize, rows: isize) -> DynResult<()> {
+        let options = self.field.options();
+
+        let field = self.field.resized(
+            options.cols.saturating_add_signed(cols),
+            options.rows.saturating_add_signed(rows),
+        );
+
+        self.renderer = Renderer::init(context, field.options(), self.settings)?;
+        self.field = field;
+        self.draw_field();
+
+        Ok(())
+    }
+
+    pub fn save(&mut self) {
+        let saved = if BoardFile::is_mbf(&self.path) {
+            self.field.to_board_file().save(&self.path)
+        } else {
+            fs::write(&self.path, self.field.to_layout()).map_err(Into::into)
+        };
+
+        let note = match saved {
+            Ok(()) => format!("saved to {}", self.path.display()),
+            Err(e) => format!("can't save: {e}"),
+        };
+
+        self.show_note(note);
+    }
+
+    // written next to the board file as a pack of one puzzle
+    pub fn export_puzzle(&mut self) {
+        let mut path = self.path.as_os_str().to_owned();
+        path.push(".puzzle");
+        let path = PathBuf::from(path);
+
+        let name = self
+            .path
+            .file_stem()
+            .map_or(String::from("edited"), |stem| {
+                stem.to_string_lossy().into_owned()
+            });
+
+        let note = match self.field.make_puzzle() {
+            Ok(puzzle) => {
+                let text = format!("name {}\n{}", name, puzzle.to_puzzle());
+
+                match fs::write(&path, text) {
+                    Ok(()) => format!("puzzle exported to {}", path.display()),
+                    Err(e) => format!("can't export: {e}"),
+                }
+            }
+            Err(e) => format!("can't export: {e}"),
+        };
+
+        self.show_note(note);
+    }
+
+    pub fn play(&mut self) {
+        self.play_requested = true;
+        self.is_active = false;
+    }
+
+    pub fn quit(&mut self) {
+        self.is_active = false;
+    }
+
+    pub fn update_display(&mut self) -> SdlResult {
+        self.renderer.render_frame()
+    }
+}
+
+// private methods
+impl EditorHandler {
+    // mines and numbers are all shown, the way a lost game ends
+    fn draw_field(&mut self) {
+        let mines = self.field.options().mines;
+
+        self.renderer.draw_initial_state(mines as i32);
+
+        for (x, y) in self.field.get_coords_all() {
+            let cell = unsafe { self.field.get_cell_unchecked(x, y) };
+            self.renderer.draw_cell_final(&cell);
+        }
+
+        self.renderer
+            .set_title_note(Some(format!("editor - {mines} mines")));
+    }
+
+    fn show_note(&mut self, note: String) {
+        self.renderer.set_title_note(Some(note));
+    }
+}
@@ -6,19 +6,22 @@ mod utils;
 use appearance::{ButtonAppearance, CellAppearance};
 use layout::Layout;
 use texture::TEXTURE_BYTES;
-use utils::WindowCanvasExtension;
+use utils::{Separator, WindowCanvasExtension};
 
 use crate::{
     game::{
-        components::minefield::{FieldOptions, MineCell},
-        GameState,
+        components::minefield::{FieldOptions, GameAnalysis, MineCell},
+        settings::{LayerView, Settings, TimerDisplay},
+        GameResult, GameState,
     },
     Context, DynResult, SdlResult,
 };
 use sdl2::{
-    image::LoadTexture,
-    rect::Rect,
+    image::ImageRWops,
+    pixels::Color,
+    rect::{Point, Rect},
     render::{Texture, WindowCanvas},
+    rwops::RWops,
 };
 use std::mem;
 
@@ -27,16 +30,27 @@ pub struct Renderer {
     render_buffer: Texture,
     texture_map: Texture,
     layout: Layout,
+    options: FieldOptions,
+    timer_display: TimerDisplay,
+    layer_view: LayerView,
+    layer: usize,
+    title_result: Option<GameResult>,
+    // mine chances tinting the hidden cells, indexed like the field
+    heatmap: Option<Vec<Vec<f64>>>,
+    hover_chance: Option<f64>,
+    // extra text such as the stats of a drill
+    title_note: Option<String>,
     copy_error: String,
     redraw_needed: bool,
 }
 
 // public methods (general)
 impl Renderer {
-    pub fn init(context: &Context, options: FieldOptions) -> DynResult<Self> {
+    pub fn init(context: &Context, options: FieldOptions, settings: Settings) -> DynResult<Self> {
         let title = "Minesweeper";
-        let width = options.cols as u32 * 16 + 24;
-        let height = options.rows as u32 * 16 + 67;
+        let (field_width, field_height) = utils::get_board_size(options, settings.layer_view);
+        let width = field_width + 24;
+        let height = field_height + 67;
         let flags_count = options.mines as i32;
 
         let canvas = context
@@ -53,17 +67,30 @@ impl Renderer {
         let render_buffer = texture_creator.create_texture_target(None, width, height)?;
         canvas.set_render_target(&render_buffer)?;
 
+        // green marks the transparent corners of non-square cells
+        let mut texture_surface = RWops::from_bytes(TEXTURE_BYTES)?.load()?;
+        texture_surface.set_color_key(true, Color::RGB(0, 255, 0))?;
+
         let mut renderer = Self {
             canvas,
             render_buffer,
-            texture_map: texture_creator.load_texture_bytes(TEXTURE_BYTES)?,
-            layout: Layout::new(width, height),
+            texture_map: texture_creator.create_texture_from_surface(&texture_surface)?,
+            layout: Layout::new(width),
+            options,
+            timer_display: settings.timer_display,
+            layer_view: settings.layer_view,
+            layer: 0,
+            title_result: None,
+            heatmap: None,
+            hover_chance: None,
+            title_note: None,
             copy_error: String::new(),
             redraw_needed: false,
         };
 
         utils::refill_buffer(&mut renderer.canvas, &renderer.texture_map)?;
         renderer.draw_initial_state(flags_count);
+        renderer.update_title();
 
         Ok(renderer)
     }
@@ -85,6 +112,68 @@ impl Renderer {
 
         Ok(())
     }
+
+    pub fn timer_step(&self) -> u64 {
+        self.timer_display.step()
+    }
+
+    // the exact time and the rating of a won game
+    pub fn set_title_result(&mut self, result: Option<GameResult>) {
+        self.title_result = result;
+        self.update_title();
+    }
+
+    pub fn set_heatmap(&mut self, heatmap: Option<Vec<Vec<f64>>>) {
+        self.heatmap = heatmap;
+        self.hover_cell(None);
+    }
+
+    // the chance of the hovered cell is shown in the title while the heatmap is on
+    pub fn hover_cell(&mut self, cell: Option<&MineCell>) {
+        let chance = cell
+            .filter(|cell| cell.is_hidden())
+            .and_then(|cell| Some(self.heatmap.as_ref()?[cell.x()][cell.y()]));
+
+        if chance != self.hover_chance {
+            self.hover_chance = chance;
+            self.update_title();
+        }
+    }
+
+    pub fn set_title_note(&mut self, note: Option<String>) {
+        self.title_note = note;
+        self.update_title();
+    }
+
+    pub fn copy_to_clipboard(&self, text: &str) -> SdlResult {
+        self.canvas
+            .window()
+            .subsystem()
+            .clipboard()
+            .set_clipboard_text(text)
+    }
+
+    // returns whether another layer has been brought into view
+    pub fn scroll_layer(&mut self, delta: i32) -> bool {
+        if self.layer_view != LayerView::Single {
+            return false;
+        }
+
+        let last = self.options.layers as i32 - 1;
+        let layer = (self.layer as i32 + delta).clamp(0, last) as usize;
+
+        if layer == self.layer {
+            return false;
+        }
+
+        self.clear_hovered_cells();
+        self.reset_target_cell();
+
+        self.layer = layer;
+        self.update_title();
+
+        true
+    }
 }
 
 // public methods (drawings)
@@ -92,7 +181,7 @@ impl Renderer {
     pub fn draw_initial_state(&mut self, flags_count: i32) {
         self.draw_button(ButtonAppearance::Happy);
         self.draw_flags_counter(flags_count);
-        self.draw_secs_counter(0);
+        self.draw_secs_digits(&utils::split_secs_by_digits(0), None);
         self.draw_blank_minefield();
     }
 
@@ -115,11 +204,17 @@ impl Renderer {
         self.draw_counter(digits, dst);
     }
 
-    pub fn draw_secs_counter(&mut self, secs: u64) {
-        let digits = utils::split_secs_by_digits(secs);
-        let dst = self.layout.secs_digit1_pos;
+    pub fn draw_secs_counter(&mut self, millis: u64) {
+        let (digits, separator) = utils::split_millis_by_digits(millis, self.timer_display);
 
-        self.draw_counter(digits, dst);
+        self.draw_secs_digits(&digits, separator);
+    }
+
+    // the time of a won game down to the centisecond, whatever the display
+    pub fn draw_win_time(&mut self, millis: u64) {
+        let (digits, separator) = utils::split_millis_by_digits(millis, TimerDisplay::Centiseconds);
+
+        self.draw_secs_digits(&digits, separator);
     }
 
     pub fn draw_cell_default(&mut self, cell: &MineCell) {
@@ -141,6 +236,35 @@ impl Renderer {
         self.draw_cell(CellAppearance::Hovered, x, y);
     }
 
+    // a safe cell is shown pressed with a green mark, a mined one keeps its
+    // cover with a red mark; both go away with the hovered cells
+    pub fn draw_hint(&mut self, cell: &MineCell, is_safe: bool) {
+        let (x, y) = (cell.x(), cell.y());
+
+        let (appearance, color) = if is_safe {
+            (CellAppearance::Hovered, Color::RGB(0, 192, 0))
+        } else {
+            (CellAppearance::Hidden, Color::RGB(255, 0, 0))
+        };
+
+        self.layout.hovered_cells.push((x, y));
+        self.draw_cell(appearance, x, y);
+        self.draw_cell_mark(color, x, y);
+    }
+
+    // a coloured mark in the middle of every judged cell
+    pub fn draw_analysis(&mut self, analysis: &GameAnalysis) {
+        for report in &analysis.moves {
+            let color = utils::get_verdict_color(report.verdict);
+            self.draw_cell_mark(color, report.x, report.y);
+        }
+    }
+
+    // a red mark on a cell answered wrong
+    pub fn draw_mistake(&mut self, x: usize, y: usize) {
+        self.draw_cell_mark(Color::RGB(255, 0, 0), x, y);
+    }
+
     pub fn clear_hovered_cells(&mut self) {
         while let Some((x, y)) = self.layout.hovered_cells.pop() {
             self.draw_cell(CellAppearance::Hidden, x, y);
@@ -154,32 +278,85 @@ impl Renderer {
         self.layout.button_pos.contains_point((x, y))
     }
 
-    pub fn minefield_contains(&self, x: i32, y: i32) -> bool {
-        self.layout.minefield_pos.contains_point((x, y))
-    }
-
     pub fn get_cell_pos(&self, x: i32, y: i32) -> Option<(usize, usize)> {
-        if self.minefield_contains(x, y) {
-            Some(utils::get_cell_pos(x, y))
-        } else {
-            None
+        let layer = match self.layer_view {
+            LayerView::SideBySide => ((x - 12) / self.get_layer_stride()).max(0) as usize,
+            LayerView::Single => self.layer,
+        };
+
+        if layer >= self.options.layers {
+            return None;
         }
+
+        let x = x - self.get_layer_shift(layer)?;
+        let (x, y) = utils::get_cell_pos(x, y, self.options)?;
+        let x = x + layer * self.options.cols;
+
+        (!self.options.is_void(x, y)).then_some((x, y))
     }
 
     pub fn is_target_changed(&self, x: i32, y: i32) -> bool {
-        match self.layout.target_pos {
-            Some(dst) => !dst.contains_point((x, y)),
-            None => self.minefield_contains(x, y),
-        }
+        self.get_cell_pos(x, y) != self.layout.target_cell
     }
 
     pub fn set_target_cell(&mut self, cell: &MineCell) {
-        let dst = utils::get_cell_dst(cell.x(), cell.y());
-        self.layout.target_pos = Some(dst);
+        self.layout.target_cell = Some((cell.x(), cell.y()));
     }
 
     pub fn reset_target_cell(&mut self) {
-        self.layout.target_pos = None;
+        self.layout.target_cell = None;
+    }
+}
+
+// private methods (general)
+impl Renderer {
+    fn update_title(&mut self) {
+        let mut title = String::from("Minesweeper");
+
+        if self.layer_view == LayerView::Single && self.options.layers > 1 {
+            title += &format!(" - layer {}/{}", self.layer + 1, self.options.layers);
+        }
+
+        if let Some(note) = &self.title_note {
+            title += &format!(" - {note}");
+        }
+
+        if let Some(chance) = self.hover_chance {
+            title += &format!(" - mine {:.0}%", chance * 100.0);
+        }
+
+        if let Some(GameResult {
+            millis,
+            rating,
+            assisted,
+            ..
+        }) = self.title_result
+        {
+            title += &format!(" - {}.{:03} s", millis / 1000, millis % 1000);
+            title += &format!(" - rating {}", rating.score);
+
+            if assisted {
+                title += " - assisted";
+            }
+        }
+
+        if let Err(error) = self.canvas.window_mut().set_title(&title) {
+            self.copy_error = error.to_string();
+        }
+    }
+
+    // horizontal distance between the same cells of two neighbouring layers
+    fn get_layer_stride(&self) -> i32 {
+        let (width, _) = utils::get_field_size(self.options);
+
+        (width + utils::LAYER_GAP) as i32
+    }
+
+    fn get_layer_shift(&self, layer: usize) -> Option<i32> {
+        match self.layer_view {
+            LayerView::SideBySide => Some(layer as i32 * self.get_layer_stride()),
+            LayerView::Single => (layer == self.layer).then_some(0),
+        }
     }
 }
 
@@ -202,33 +379,152 @@ impl Renderer {
         self.redraw_needed = true;
     }
 
-    fn draw_cell(&mut self, appearance: CellAppearance, x: usize, y: usize) {
-        let src = utils::get_appearance_src(appearance);
-        let dst = utils::get_cell_dst(x, y);
+    fn draw_secs_digits(&mut self, digits: &[i32], separator: Option<(usize, Separator)>) {
+        let extra_width = (digits.len() as i32 - 3) * 13;
+
+        if digits.len() != self.layout.secs_digits_count || separator.is_some() {
+            self.draw_secs_frame(digits.len());
+        }
+
+        let mut dst = self.layout.secs_digit1_pos.left_shifted(extra_width);
+        let digit1_pos = dst;
+
+        for &digit in digits {
+            self.draw_part(utils::get_digit_src(digit), dst);
+            dst.set_x(dst.x + 13);
+        }
+
+        if let Some((index, separator)) = separator {
+            let dot = Rect::new(digit1_pos.x + index as i32 * 13 - 2, digit1_pos.y, 2, 2);
+            let color = Color::RGB(255, 0, 0);
+
+            match separator {
+                Separator::Point => self.fill_part(color, dot.bottom_shifted(19)),
+                Separator::Colon => {
+                    self.fill_part(color, dot.bottom_shifted(5));
+                    self.fill_part(color, dot.bottom_shifted(14));
+                }
+            }
+        }
 
-        self.draw_part(src, dst);
         self.redraw_needed = true;
     }
 
-    fn draw_blank_minefield(&mut self) {
-        let minefield_pos = self.layout.minefield_pos;
-        let field_width = minefield_pos.width() as i32;
-        let field_height = minefield_pos.height() as i32;
+    // the counter frame grows to the left when more than three digits are shown
+    fn draw_secs_frame(&mut self, digits_count: usize) {
+        let src = utils::get_counter_src();
+        let dst = self.layout.secs_frame_pos;
+
+        let old_extra_width = (self.layout.secs_digits_count as i32 - 3) * 13;
+        let extra_width = (digits_count as i32 - 3) * 13;
+
+        if old_extra_width > extra_width {
+            let dst = Rect::new(
+                dst.x - old_extra_width,
+                dst.y,
+                old_extra_width as u32,
+                dst.height(),
+            );
+            self.fill_part(Color::RGB(192, 192, 192), dst);
+        }
+
+        self.draw_part(src, dst);
+
+        for shift in (13..=extra_width).step_by(13) {
+            let src = Rect::new(src.x, src.y, 15, src.height());
+            let dst = Rect::new(dst.x - shift, dst.y, 15, dst.height());
 
-        let src = utils::get_appearance_src(CellAppearance::Hidden);
-        let dst = utils::get_cell_dst(0, 0);
+            self.draw_part(src, dst);
+        }
+
+        self.layout.secs_digits_count = digits_count;
+    }
+
+    // cells of the layers out of view are skipped
+    fn draw_cell(&mut self, appearance: CellAppearance, x: usize, y: usize) {
+        let cols = self.options.cols;
+        let layer = x / cols;
+
+        let Some(shift) = self.get_layer_shift(layer) else {
+            return;
+        };
 
-        for x in (0..field_width).step_by(16) {
-            let dst = dst.right_shifted(x);
+        let x = x % cols;
+        let shape = utils::get_cell_shape(x, y, self.options.tiling);
+        let src = utils::get_appearance_src(appearance, shape);
+        let dst = utils::get_cell_dst(x, y, self.options.tiling).right_shifted(shift);
 
-            for y in (0..field_height).step_by(16) {
-                self.draw_part(src, dst.bottom_shifted(y));
+        // hidden cells are tinted by their mine chance
+        let chance = match (appearance, &self.heatmap) {
+            (CellAppearance::Hidden, Some(heatmap)) => Some(heatmap[x + layer * cols][y]),
+            _ => None,
+        };
+
+        if let Some(chance) = chance {
+            let (r, g, b) = utils::get_chance_color(chance).rgb();
+            self.texture_map.set_color_mod(r, g, b);
+        }
+
+        self.draw_part(src, dst);
+        self.texture_map.set_color_mod(255, 255, 255);
+
+        if let CellAppearance::Num(n) = appearance {
+            if !utils::has_number_src(n, shape) {
+                self.draw_small_number(n, utils::get_number_center(dst, shape));
             }
         }
 
         self.redraw_needed = true;
     }
 
+    fn draw_cell_mark(&mut self, color: Color, x: usize, y: usize) {
+        let cols = self.options.cols;
+
+        let Some(shift) = self.get_layer_shift(x / cols) else {
+            return;
+        };
+
+        let x = x % cols;
+        let shape = utils::get_cell_shape(x, y, self.options.tiling);
+        let dst = utils::get_cell_dst(x, y, self.options.tiling).right_shifted(shift);
+        let center = utils::get_number_center(dst, shape);
+
+        self.fill_part(
+            Color::RGB(0, 0, 0),
+            Rect::new(center.x - 4, center.y - 4, 8, 8),
+        );
+        self.fill_part(color, Rect::new(center.x - 3, center.y - 3, 6, 6));
+
+        self.redraw_needed = true;
+    }
+
+    fn draw_small_number(&mut self, number: u16, center: Point) {
+        let digits = number.to_string();
+        let width = digits.len() as i32 * 6 - 1;
+        let mut dst = Rect::new(center.x - width / 2, center.y - 3, 5, 7);
+
+        let (r, g, b) = utils::get_number_color(number).rgb();
+        self.texture_map.set_color_mod(r, g, b);
+
+        for digit in digits.bytes() {
+            self.draw_part(utils::get_small_digit_src(digit - b'0'), dst);
+            dst.set_x(dst.x + 6);
+        }
+
+        self.texture_map.set_color_mod(255, 255, 255);
+    }
+
+    fn draw_blank_minefield(&mut self) {
+        let options = self.options;
+
+        // void cells are left as background
+        for x in 0..options.total_cols() {
+            for y in (0..options.rows).filter(|&y| !options.is_void(x, y)) {
+                self.draw_cell(CellAppearance::Hidden, x, y);
+            }
+        }
+    }
+
     fn draw_part(&mut self, src: Rect, dst: Rect) {
         let result = self.canvas.copy(&self.texture_map, src, dst);
 
@@ -236,4 +532,13 @@ impl Renderer {
             self.copy_error = error;
         };
     }
+
+    fn fill_part(&mut self, color: Color, dst: Rect) {
+        self.canvas.set_draw_color(color);
+        let result = self.canvas.fill_rect(dst);
+
+        if let Err(error) = result {
+            self.copy_error = error;
+        };
+    }
 }
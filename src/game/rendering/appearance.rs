@@ -1,5 +1,5 @@
 use crate::game::{
-    components::minefield::{CellState, MineCell},
+    components::minefield::{CellKind, CellState, MineCell},
     GameState,
 };
 
@@ -11,12 +11,13 @@ pub enum ButtonAppearance {
     Hovered,
 }
 
+#[derive(Clone, Copy)]
 pub enum CellAppearance {
-    Num(u8),
+    Num(u16),
     Hidden,
     Hovered,
     Mined,
-    Flagged,
+    Flagged(u8),
     Wrong,
     Blown,
 }
@@ -39,23 +40,24 @@ impl CellAppearance {
     pub fn from_cell_default(cell: &MineCell) -> Self {
         match cell.state() {
             CellState::Hidden => Self::Hidden,
-            CellState::Flagged => Self::Flagged,
-            CellState::Revealed => match cell.kind().to_int() {
-                n @ 0..=8 => Self::Num(n),
-                _ => Self::Blown,
+            CellState::Flagged(flags) => Self::Flagged(flags),
+            CellState::Revealed => match cell.kind() {
+                CellKind::Num(n) => Self::Num(n),
+                CellKind::Mined(_) => Self::Blown,
             },
         }
     }
 
     pub fn from_cell_final(cell: &MineCell) -> Option<Self> {
         let appearance = match cell.state() {
-            CellState::Hidden => match cell.kind().to_int() {
-                n @ 0..=8 => Self::Num(n),
-                _ => Self::Mined,
+            CellState::Hidden => match cell.kind() {
+                CellKind::Num(n) => Self::Num(n),
+                CellKind::Mined(_) => Self::Mined,
             },
-            CellState::Flagged => match cell.kind().to_int() {
-                0..=8 => Self::Wrong,
-                _ => return None,
+            // the flags have to match the mines of the cell
+            CellState::Flagged(flags) => match cell.kind() {
+                CellKind::Mined(mines) if mines == flags => return None,
+                _ => Self::Wrong,
             },
             CellState::Revealed => return None,
         };
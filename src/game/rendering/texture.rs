@@ -1,12 +1,543 @@
 pub const TEXTURE_BYTES: &[u8] = &[
-    0x42, 0x4D, 0xC6, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x76, 0x00, 0x00, 0x00, 0x28, 0x00,
-    0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x7A, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x50, 0x22, 0x00, 0x00, 0xC4, 0x0E, 0x00, 0x00, 0xC4, 0x0E, 0x00, 0x00, 0x00, 0x00,
+    0x42, 0x4D, 0xF6, 0x43, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x76, 0x00, 0x00, 0x00, 0x28, 0x00,
+    0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0xF0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x80, 0x43, 0x00, 0x00, 0xC4, 0x0E, 0x00, 0x00, 0xC4, 0x0E, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x80,
     0x00, 0x00, 0x00, 0x80, 0x80, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x80,
     0x00, 0x00, 0xC0, 0xC0, 0xC0, 0x00, 0x80, 0x80, 0x80, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0xFF,
     0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0xFF,
-    0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x78, 0x88, 0x88, 0x88, 0x88, 0x88, 0xA8, 0xA8, 0x88, 0x88,
+    0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0xA8, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x7F, 0xFA, 0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8,
+    0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF,
+    0x77, 0x77, 0x70, 0x07, 0x70, 0x07, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xFF, 0x77, 0x70, 0x07, 0x70,
+    0x07, 0x70, 0x07, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x77,
+    0xFF, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x07, 0x77, 0xFF, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88,
+    0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF,
+    0xF7, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x07, 0x77,
+    0x07, 0x77, 0x07, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x78, 0x8A, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x79, 0x97, 0x79, 0x97, 0x77, 0xFF,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x79, 0x97, 0x79, 0x97, 0x79, 0x97, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77,
+    0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xFF, 0x77, 0x99, 0x97, 0x99, 0x97, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x99, 0x97, 0x99,
+    0x97, 0x99, 0x97, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x79, 0x97, 0x79, 0x97, 0x7F, 0xFA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF9, 0x97, 0x79, 0x97, 0x79, 0x9F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xA8, 0x87, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77,
+    0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77,
+    0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x78, 0x8A, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77,
+    0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77,
+    0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0x8A,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x7F, 0xFA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xA8, 0x87, 0x70, 0x07, 0x70, 0x07, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x80, 0x07, 0x70,
+    0x07, 0x70, 0x08, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xFF, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x88,
+    0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x07, 0x77, 0x07, 0x77, 0x07, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0xFF, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x07, 0x77,
+    0x07, 0x77, 0x07, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAF, 0xF7, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7,
+    0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x79, 0x97, 0x79, 0x97, 0x77, 0x78,
+    0x8A, 0xAA, 0xAA, 0xA8, 0x87, 0x79, 0x97, 0x79, 0x97, 0x79, 0x97, 0x78, 0x8A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x99, 0x97, 0x99, 0x97, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88, 0x77, 0x99, 0x97, 0x99,
+    0x97, 0x99, 0x97, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF,
+    0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x79, 0x97, 0x79, 0x97, 0x77, 0x77,
+    0x88, 0xAA, 0xAA, 0x88, 0x77, 0x79, 0x97, 0x79, 0x97, 0x79, 0x97, 0x77, 0x88, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF,
+    0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFA, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x78, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x78, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0xAA, 0xAA,
+    0x88, 0x88, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xF7, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0xF7, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0xAA, 0x88, 0x88, 0x77, 0x77, 0x88, 0x88, 0xAA, 0xAA, 0x88,
+    0x88, 0x77, 0x77, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77,
+    0x70, 0x07, 0x70, 0x07, 0x77, 0x88, 0xFF, 0x70, 0x07, 0x70, 0x07, 0x70, 0x07, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x70, 0x07, 0x70, 0x07, 0x77, 0x88, 0xFF, 0x70,
+    0x07, 0x70, 0x07, 0x70, 0x07, 0x88, 0xFF, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x88, 0xFF, 0x77,
+    0x07, 0x77, 0x07, 0x77, 0x07, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x07, 0x77, 0x07, 0x77, 0x88, 0xFF, 0x77, 0x07, 0x77, 0x07, 0x77, 0x07, 0x88, 0xFF, 0x77,
+    0x77, 0x07, 0x77, 0x07, 0x77, 0x88, 0xFF, 0x77, 0x07, 0x77, 0x07, 0x77, 0x07, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x07, 0x77, 0x07, 0x77, 0x88, 0xFF, 0x77,
+    0x07, 0x77, 0x07, 0x77, 0x07, 0x88, 0xFF, 0x77, 0x79, 0x97, 0x79, 0x97, 0x77, 0x88, 0xFF, 0x79,
+    0x97, 0x79, 0x97, 0x79, 0x97, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x79, 0x97, 0x79, 0x97, 0x77, 0x88, 0xFF, 0x79, 0x97, 0x79, 0x97, 0x79, 0x97, 0x88, 0xFF, 0x77,
+    0x99, 0x97, 0x99, 0x97, 0x77, 0x88, 0xFF, 0x99, 0x97, 0x99, 0x97, 0x99, 0x97, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x99, 0x97, 0x99, 0x97, 0x77, 0x88, 0xFF, 0x99,
+    0x97, 0x99, 0x97, 0x99, 0x97, 0x88, 0xFF, 0x77, 0x79, 0x97, 0x79, 0x97, 0x77, 0x88, 0xFF, 0x79,
+    0x97, 0x79, 0x97, 0x79, 0x97, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x79, 0x97, 0x79, 0x97, 0x77, 0x88, 0xFF, 0x79, 0x97, 0x79, 0x97, 0x79, 0x97, 0x88, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0xFF,
+    0x77, 0x77, 0x77, 0x77, 0xFF, 0xFF, 0xFF, 0xFF, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xFF, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xFF, 0xFF, 0x77, 0x77, 0xFF, 0xFF, 0xAA, 0xAA, 0xFF,
+    0xFF, 0x77, 0x77, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x78, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x78, 0xAA, 0xAA,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF7, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF7, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFF,
+    0xAA, 0xAF, 0xFF, 0xFA, 0xFF, 0xFF, 0xFA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAF, 0xFA, 0xAF, 0xFF, 0xAA,
+    0xAF, 0xFF, 0xAA, 0xAF, 0xFA, 0xAA, 0xAF, 0xFF, 0xAA, 0xAF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xAF, 0xFA, 0xAA, 0xFF, 0xAA, 0xFF, 0xAA, 0xAA, 0xAA,
+    0xAF, 0xFA, 0xAA, 0xAF, 0xFA, 0xFF, 0xAF, 0xFA, 0xFF, 0xAF, 0xFA, 0xAF, 0xFA, 0xAA, 0xFF, 0xAF,
+    0xFA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xAF,
+    0xFA, 0xAA, 0xFF, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAF, 0xFA, 0xFF, 0xFF, 0xFA, 0xAA, 0xAF, 0xFA,
+    0xFF, 0xAF, 0xFA, 0xAF, 0xFA, 0xAA, 0xFF, 0xAF, 0xFA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xAF, 0xFA, 0xAA, 0xFF, 0xAA, 0xAA, 0xFF, 0xAA, 0xAF,
+    0xFF, 0xAA, 0xFF, 0xAF, 0xFA, 0xAA, 0xAF, 0xFA, 0xFF, 0xAF, 0xFA, 0xAA, 0xFF, 0xAA, 0xAF, 0xFF,
+    0xAA, 0xAF, 0xFF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xAF,
+    0xFA, 0xAA, 0xFF, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAF, 0xFA, 0xAF, 0xFF, 0xFA, 0xFF, 0xFF, 0xAA,
+    0xFF, 0xFF, 0xAA, 0xAA, 0xFF, 0xAA, 0xFF, 0xAF, 0xFA, 0xFF, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xAF, 0xFA, 0xAF, 0xFF, 0xAA, 0xFF, 0xAF, 0xFA, 0xAA,
+    0xAF, 0xFA, 0xAA, 0xFF, 0xFA, 0xFF, 0xAA, 0xAA, 0xFF, 0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xFF, 0xAF,
+    0xFA, 0xFF, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFF,
+    0xAA, 0xAA, 0xFF, 0xAA, 0xAF, 0xFF, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAF, 0xFA, 0xFF, 0xFF, 0xFA,
+    0xAF, 0xFF, 0xAA, 0xFF, 0xFF, 0xFA, 0xAF, 0xFF, 0xAA, 0xAF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7,
+    0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x7A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xA7, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9,
+    0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xA7, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8,
+    0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x7A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77,
+    0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x99,
+    0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88,
+    0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xA8, 0x87, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77,
+    0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x7A, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0x99,
+    0x99, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77,
+    0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77,
+    0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99,
+    0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x78, 0x8A, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77,
+    0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0x99, 0x99, 0x99, 0x99, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xA8, 0x87, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77,
+    0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0x99, 0x99,
+    0x99, 0x99, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0x70, 0x70, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x00,
+    0x00, 0x07, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x79, 0x70, 0x70, 0x79, 0x77, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99, 0x90, 0x90, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xA8, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x00, 0x00, 0x07, 0x77, 0x7A,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x00, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xA7, 0x77, 0x77, 0x90, 0x00, 0x97, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0x99, 0x99, 0x00,
+    0x00, 0x09, 0x99, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0x8A,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xA7, 0x77, 0x70, 0x00, 0x00, 0x00, 0x77, 0x7A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77,
+    0x70, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA7, 0x77, 0x70, 0x09, 0x09, 0x00, 0x77, 0x7A,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0x99, 0x90, 0x00, 0x00, 0x00, 0x99, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x70, 0x00, 0x00, 0x00, 0x77, 0x77,
+    0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x79, 0x99, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x70, 0x00, 0x90, 0x00, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99, 0x90, 0x00,
+    0x00, 0x00, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88,
+    0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x70, 0x0F, 0x00, 0x00, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x99,
+    0x99, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x70, 0x09, 0x09, 0x00, 0x77, 0x77,
+    0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99, 0x90, 0x0F, 0x00, 0x00, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xA8,
+    0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xA7, 0x77, 0x77, 0x77, 0x00, 0x00, 0x07, 0x77, 0x77,
+    0x7A, 0xAA, 0xAA, 0xA8, 0x87, 0x77, 0x77, 0x79, 0x99, 0x77, 0x77, 0x78, 0x8A, 0xAA, 0xAA, 0xA7,
+    0x77, 0x77, 0x77, 0x90, 0x00, 0x97, 0x77, 0x77, 0x7A, 0xAA, 0xAA, 0xA9, 0x99, 0x99, 0x99, 0x00,
+    0x00, 0x09, 0x99, 0x99, 0x9A, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x88, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x70, 0x70, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77,
+    0x99, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x79, 0x70, 0x70, 0x79, 0x77, 0x77,
+    0x77, 0xAA, 0xAA, 0x99, 0x99, 0x99, 0x99, 0x90, 0x90, 0x99, 0x99, 0x99, 0x99, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x99, 0x99, 0x99, 0x99, 0x99,
+    0x99, 0x99, 0x99, 0x99, 0x99, 0xAA, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xA7, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x7A, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xA7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x7A, 0xA9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, 0xAF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xA8, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0xA8, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x8A, 0xAF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFA, 0xA8, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0xA8, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x88, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x78, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x78, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x78, 0x89, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x98, 0xA8, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x8A, 0xA8, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x8A, 0xA8, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xA8, 0x99, 0x99, 0x99, 0x99, 0x99,
+    0x99, 0x99, 0x99, 0x99, 0x99, 0x8A, 0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x7F, 0xFA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xA8, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x7F, 0xFA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x8A, 0xA8, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x8A, 0xAA, 0xFF,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x70, 0x70, 0x77, 0x77, 0x77,
+    0x78, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x00, 0x00, 0x07, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0x87,
+    0x77, 0x77, 0x79, 0x70, 0x70, 0x79, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0x89, 0x99, 0x99, 0x99, 0x90,
+    0x90, 0x99, 0x99, 0x99, 0x98, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0xFF, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0x87,
+    0x77, 0x77, 0x77, 0x00, 0x00, 0x07, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77,
+    0x00, 0x77, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x90, 0x00, 0x97, 0x77, 0x77,
+    0x78, 0xAA, 0xAA, 0x89, 0x99, 0x99, 0x99, 0x00, 0x00, 0x09, 0x99, 0x99, 0x98, 0xAA, 0xAA, 0xAF,
+    0xF7, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x70, 0x00, 0x00, 0x00, 0x77, 0x77,
+    0x8A, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x77, 0x70, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xA8,
+    0x77, 0x77, 0x70, 0x09, 0x09, 0x00, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xA8, 0x99, 0x99, 0x90, 0x00,
+    0x00, 0x00, 0x99, 0x99, 0x8A, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xFF,
+    0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x87, 0x77, 0x70, 0x00, 0x00, 0x00, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x79,
+    0x99, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x70, 0x00, 0x90, 0x00, 0x77, 0x78,
+    0xAA, 0xAA, 0xAA, 0xAA, 0x89, 0x99, 0x90, 0x00, 0x00, 0x00, 0x99, 0x98, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x70, 0x0F, 0x00, 0x00, 0x77, 0x78,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x99, 0x99, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x87, 0x77, 0x70, 0x09, 0x09, 0x00, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0x89, 0x99, 0x90, 0x0F,
+    0x00, 0x00, 0x99, 0x98, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77, 0x7F, 0xFA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xA8, 0x77, 0x77, 0x00, 0x00, 0x07, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x79,
+    0x99, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x90, 0x00, 0x97, 0x77, 0x8A,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x99, 0x99, 0x00, 0x00, 0x09, 0x99, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAF, 0xF7, 0x77, 0x77, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x77,
+    0x77, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x70, 0x70, 0x77, 0x77, 0x8A,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x99, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xA8, 0x77, 0x79, 0x70, 0x70, 0x79, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x99, 0x99, 0x90,
+    0x90, 0x99, 0x99, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77,
+    0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x77, 0x77, 0x78, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x89, 0x99, 0x99, 0x99, 0x99, 0x98, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77,
+    0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x99, 0x99,
+    0x99, 0x99, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77, 0x77, 0x7F, 0xFA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x77,
+    0x77, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x77, 0x77, 0x8A, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x99, 0x99, 0x99, 0x99, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xFF, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77,
+    0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x78, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0x87, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x89, 0x99,
+    0x99, 0x98, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0xFF, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0x87, 0x77, 0x77, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x77, 0x77, 0x78, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x89, 0x99, 0x99, 0x98, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAF, 0xF7, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77,
+    0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x8A, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xF7, 0x7F, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xA8, 0x77, 0x77, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x99,
+    0x99, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0x87, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF,
+    0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x78, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x89, 0x98, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87,
+    0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x87, 0x78, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0x87, 0x78, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x89,
+    0x98, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF,
+    0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8,
+    0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAF, 0xFA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xA8, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8,
+    0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x88, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x07, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x07, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x99, 0x99, 0x09, 0x99, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0x77, 0x77, 0x88, 0x88, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x07, 0x77, 0x77, 0xAA, 0xAA, 0x88,
+    0x88, 0x77, 0x77, 0x88, 0x88, 0xAA, 0xAA, 0x79, 0x97, 0x77, 0x07, 0x77, 0x99, 0xAA, 0xAA, 0x99,
+    0x99, 0x99, 0x09, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x07, 0x00, 0x00, 0x07, 0x07, 0x77, 0x88, 0x88, 0x00, 0x00, 0x00, 0x00, 0x88, 0x88, 0x77, 0x77,
+    0x99, 0x00, 0x00, 0x09, 0x97, 0x77, 0x99, 0x99, 0x09, 0x00, 0x00, 0x09, 0x09, 0x99, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x70, 0x00, 0x00, 0x00, 0x77, 0x77, 0x88, 0x77,
+    0x00, 0x00, 0x00, 0x00, 0x77, 0x88, 0x87, 0x77, 0x79, 0x90, 0x00, 0x99, 0x77, 0x77, 0x89, 0x99,
+    0x90, 0x00, 0x00, 0x00, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x00, 0x00, 0x00, 0x00, 0x07, 0x77, 0xFF, 0x77, 0x77, 0x00, 0x00, 0x77, 0x77, 0x88, 0x87, 0x77,
+    0x00, 0x99, 0x09, 0x90, 0x07, 0x77, 0x89, 0x99, 0x00, 0x00, 0x00, 0x00, 0x09, 0x99, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x00, 0x00, 0x00, 0x00, 0x07, 0x77, 0xFF, 0x77,
+    0x77, 0x77, 0x07, 0x77, 0x77, 0x88, 0x87, 0x77, 0x00, 0x09, 0x99, 0x00, 0x07, 0x77, 0x89, 0x99,
+    0x00, 0x00, 0x00, 0x00, 0x09, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xFF, 0x77, 0x77, 0x77, 0x07, 0x77, 0x77, 0x88, 0x87, 0x00,
+    0x00, 0x09, 0x99, 0x00, 0x00, 0x07, 0x89, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x00, 0xFF, 0x00, 0x00, 0x07, 0x77, 0xFF, 0x77,
+    0x77, 0x79, 0x97, 0x77, 0x77, 0x88, 0x87, 0x77, 0x00, 0x99, 0x09, 0x90, 0x07, 0x77, 0x89, 0x99,
+    0x00, 0xFF, 0x00, 0x00, 0x09, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x00, 0xFF, 0x00, 0x00, 0x07, 0x77, 0xFF, 0x77, 0x79, 0x99, 0x97, 0x77, 0x77, 0x88, 0x87, 0x77,
+    0x09, 0x9F, 0x00, 0x99, 0x07, 0x77, 0x89, 0x99, 0x00, 0xFF, 0x00, 0x00, 0x09, 0x99, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x70, 0x00, 0x00, 0x00, 0x77, 0x77, 0xFF, 0x77,
+    0x99, 0x99, 0x97, 0x77, 0x77, 0x88, 0x87, 0x77, 0x99, 0x00, 0x00, 0x09, 0x97, 0x77, 0x89, 0x99,
+    0x90, 0x00, 0x00, 0x00, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x07, 0x00, 0x00, 0x07, 0x07, 0x77, 0xFF, 0x77, 0x79, 0x99, 0x97, 0x77, 0x77, 0x88, 0x87, 0x79,
+    0x97, 0x00, 0x00, 0x07, 0x99, 0x77, 0x89, 0x99, 0x09, 0x00, 0x00, 0x09, 0x09, 0x99, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xFF, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x07, 0x77, 0x77, 0x77, 0xFF, 0x77,
+    0x77, 0x79, 0x97, 0x77, 0x77, 0xFF, 0x87, 0x99, 0x77, 0x77, 0x07, 0x77, 0x79, 0x97, 0x89, 0x99,
+    0x99, 0x99, 0x09, 0x99, 0x99, 0x99, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF,
+    0x77, 0x77, 0x77, 0x77, 0xFF, 0xFF, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77,
+    0x77, 0x77, 0x07, 0x77, 0x77, 0x88, 0xFF, 0xFF, 0x77, 0x77, 0x77, 0x77, 0xFF, 0xFF, 0x88, 0x77,
+    0x77, 0x77, 0x07, 0x77, 0x77, 0x88, 0x88, 0x99, 0x99, 0x99, 0x09, 0x99, 0x99, 0x88, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0x77, 0x77, 0xFF, 0xFF, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xFF,
+    0xFF, 0x77, 0x77, 0xFF, 0xFF, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88,
+    0x99, 0x99, 0x99, 0x99, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x99, 0x99, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0xAA, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0xAA, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77,
+    0x7C, 0xCC, 0xCC, 0xCC, 0x77, 0x77, 0x77, 0x72, 0x22, 0x22, 0x22, 0x22, 0x27, 0x77, 0x77, 0x79,
+    0x99, 0x99, 0x99, 0x99, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x74, 0x44, 0x77, 0x77, 0x77, 0x71,
+    0x11, 0x11, 0x11, 0x11, 0x77, 0x77, 0x77, 0x77, 0x66, 0x66, 0x66, 0x66, 0x77, 0x77, 0x77, 0x77,
+    0x77, 0x70, 0x00, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x88, 0x88, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x7C, 0xCC, 0xCC, 0xCC, 0x77, 0x77, 0x87, 0x72,
+    0x22, 0x22, 0x22, 0x22, 0x27, 0x77, 0x87, 0x79, 0x99, 0x99, 0x99, 0x99, 0x97, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x74, 0x44, 0x77, 0x77, 0x87, 0x71, 0x11, 0x11, 0x11, 0x11, 0x17, 0x77, 0x87, 0x76,
+    0x66, 0x66, 0x66, 0x66, 0x67, 0x77, 0x87, 0x77, 0x77, 0x70, 0x00, 0x77, 0x77, 0x77, 0x87, 0x78,
+    0x88, 0x88, 0x88, 0x88, 0x87, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x7C, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x72, 0x22, 0x27, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x99, 0x97, 0x77, 0x87, 0x77, 0x77, 0x77, 0x74, 0x44, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x11, 0x17, 0x77, 0x87, 0x76, 0x66, 0x77, 0x77, 0x66, 0x67, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x00, 0x07, 0x77, 0x77, 0x87, 0x78, 0x88, 0x77, 0x77, 0x88, 0x87, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x7C, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x22, 0x22, 0x27, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x99, 0x97, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x74, 0x44, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x11, 0x17, 0x77, 0x87, 0x76,
+    0x66, 0x77, 0x77, 0x66, 0x67, 0x77, 0x87, 0x77, 0x77, 0x77, 0x00, 0x07, 0x77, 0x77, 0x87, 0x78,
+    0x88, 0x77, 0x77, 0x88, 0x87, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x7C, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x22, 0x22, 0x27, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x99, 0x99, 0x99, 0x77, 0x77, 0x87, 0x74, 0x44, 0x44, 0x44, 0x44, 0x47, 0x77, 0x87, 0x71,
+    0x11, 0x11, 0x11, 0x11, 0x17, 0x77, 0x87, 0x76, 0x66, 0x66, 0x66, 0x66, 0x67, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x70, 0x00, 0x77, 0x77, 0x87, 0x77, 0x88, 0x88, 0x88, 0x88, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x7C, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x22, 0x22, 0x77, 0x77, 0x87, 0x77, 0x77, 0x99, 0x99, 0x99, 0x77, 0x77, 0x87, 0x74,
+    0x44, 0x44, 0x44, 0x44, 0x47, 0x77, 0x87, 0x71, 0x11, 0x11, 0x11, 0x11, 0x77, 0x77, 0x87, 0x76,
+    0x66, 0x66, 0x66, 0x66, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x70, 0x00, 0x77, 0x77, 0x87, 0x77,
+    0x88, 0x88, 0x88, 0x88, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x7C, 0xCC, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x22, 0x27, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x99, 0x97, 0x77, 0x87, 0x77, 0x44, 0x47, 0x74, 0x44, 0x77, 0x77, 0x87, 0x71,
+    0x11, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x76, 0x66, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x00, 0x07, 0x77, 0x87, 0x78, 0x88, 0x77, 0x77, 0x88, 0x87, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0xCC, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x72,
+    0x22, 0x77, 0x77, 0x22, 0x27, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x99, 0x97, 0x77, 0x87, 0x77,
+    0x44, 0x47, 0x74, 0x44, 0x77, 0x77, 0x87, 0x71, 0x11, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x76,
+    0x66, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x00, 0x07, 0x77, 0x87, 0x78,
+    0x88, 0x77, 0x77, 0x88, 0x87, 0x77, 0x87, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x77, 0x7C, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x72, 0x22, 0x22, 0x22, 0x22, 0x27, 0x77, 0x87, 0x79,
+    0x99, 0x99, 0x99, 0x99, 0x97, 0x77, 0x87, 0x77, 0x74, 0x44, 0x74, 0x44, 0x77, 0x77, 0x87, 0x71,
+    0x11, 0x11, 0x11, 0x11, 0x17, 0x77, 0x87, 0x76, 0x66, 0x66, 0x66, 0x66, 0x77, 0x77, 0x87, 0x70,
+    0x00, 0x00, 0x00, 0x00, 0x07, 0x77, 0x87, 0x78, 0x88, 0x88, 0x88, 0x88, 0x87, 0x77, 0x87, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x87, 0x77, 0x77, 0x77, 0xCC, 0x77, 0x77, 0x77, 0x87, 0x77,
+    0x22, 0x22, 0x22, 0x22, 0x77, 0x77, 0x87, 0x79, 0x99, 0x99, 0x99, 0x99, 0x77, 0x77, 0x87, 0x77,
+    0x74, 0x44, 0x74, 0x44, 0x77, 0x77, 0x87, 0x71, 0x11, 0x11, 0x11, 0x11, 0x17, 0x77, 0x87, 0x77,
+    0x66, 0x66, 0x66, 0x66, 0x77, 0x77, 0x87, 0x70, 0x00, 0x00, 0x00, 0x00, 0x07, 0x77, 0x87, 0x77,
+    0x88, 0x88, 0x88, 0x88, 0x77, 0x77, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77,
+    0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0x88, 0x77, 0x77, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0x88,
+    0x77, 0x77, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x77, 0x77, 0x88, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    0xAA, 0x88, 0x88, 0xAA, 0xAA, 0xAA, 0x78, 0x88, 0x88, 0x88, 0x88, 0x88, 0xA8, 0xA8, 0x88, 0x88,
     0x88, 0x88, 0x88, 0x8A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
     0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
     0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
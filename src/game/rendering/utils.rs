@@ -1,13 +1,34 @@
 use super::appearance::{ButtonAppearance, CellAppearance};
-use crate::SdlResult;
+use crate::{
+    game::{
+        components::minefield::{FieldOptions, Tiling, Verdict},
+        settings::{LayerView, TimerDisplay},
+    },
+    SdlResult,
+};
 use sdl2::{
     pixels::Color,
-    rect::Rect,
+    rect::{Point, Rect},
     render::{Texture, WindowCanvas},
     sys::{SDL_SetRenderTarget, SDL_Texture},
 };
 use std::ptr;
 
+pub const LAYER_GAP: u32 = 8;
+
+pub enum Separator {
+    Point,
+    Colon,
+}
+
+#[derive(Clone, Copy)]
+pub enum CellShape {
+    Square,
+    Hex,
+    TriangleUp,
+    TriangleDown,
+}
+
 pub fn get_button_src(appearance: ButtonAppearance) -> Rect {
     let x = match appearance {
         ButtonAppearance::Happy => 0,
@@ -20,29 +41,129 @@ pub fn get_button_src(appearance: ButtonAppearance) -> Rect {
     Rect::new(x, 55, 26, 26)
 }
 
-pub fn get_appearance_src(appearance: CellAppearance) -> Rect {
-    let mut y = 16;
+pub fn get_appearance_src(appearance: CellAppearance, shape: CellShape) -> Rect {
+    if let CellAppearance::Flagged(flags @ 2..) = appearance {
+        return get_multi_flag_src(flags, shape);
+    }
+
+    let (mut y, height) = match shape {
+        CellShape::Square => (16, 16),
+        CellShape::Hex => (139, 17),
+        CellShape::TriangleUp => return get_triangle_src(appearance, 156),
+        CellShape::TriangleDown => return get_triangle_src(appearance, 176),
+    };
 
     let x = match appearance {
         CellAppearance::Num(n) => {
-            y = 0;
-            n as i32 * 16
+            y -= height;
+
+            if has_number_src(n, shape) {
+                n as i32 * 16
+            } else {
+                0
+            }
         }
         CellAppearance::Hidden => 0,
         CellAppearance::Hovered => 16,
         CellAppearance::Mined => 32,
-        CellAppearance::Flagged => 48,
+        CellAppearance::Flagged(_) => 48,
         CellAppearance::Wrong => 64,
         CellAppearance::Blown => 80,
     };
 
-    Rect::new(x, y, 16, 16)
+    Rect::new(x, y, 16, height as u32)
+}
+
+fn get_triangle_src(appearance: CellAppearance, y: i32) -> Rect {
+    let index = match appearance {
+        CellAppearance::Hidden => 0,
+        CellAppearance::Num(_) | CellAppearance::Hovered => 1,
+        CellAppearance::Mined => 2,
+        CellAppearance::Flagged(_) => 3,
+        CellAppearance::Wrong => 4,
+        CellAppearance::Blown => 5,
+    };
+
+    Rect::new(index * 24, y, 24, 20)
+}
+
+// two and three flags have their own sprites
+fn get_multi_flag_src(flags: u8, shape: CellShape) -> Rect {
+    let index = flags.min(3) as i32 - 2;
+
+    match shape {
+        CellShape::Square => Rect::new(index * 16, 203, 16, 16),
+        CellShape::Hex => Rect::new(index * 16 + 32, 203, 16, 17),
+        CellShape::TriangleUp => Rect::new(index * 24, 220, 24, 20),
+        CellShape::TriangleDown => Rect::new(index * 24 + 48, 220, 24, 20),
+    }
+}
+
+// other numbers are drawn with small digits over a blank cell
+pub fn has_number_src(number: u16, shape: CellShape) -> bool {
+    match shape {
+        CellShape::Square | CellShape::Hex => number <= 8,
+        CellShape::TriangleUp | CellShape::TriangleDown => number == 0,
+    }
+}
+
+pub fn get_small_digit_src(digit: u8) -> Rect {
+    Rect::new(digit as i32 * 6, 196, 5, 7)
+}
+
+pub fn get_number_color(number: u16) -> Color {
+    const COLORS: [(u8, u8, u8); 8] = [
+        (0, 0, 255),
+        (0, 128, 0),
+        (255, 0, 0),
+        (0, 0, 128),
+        (128, 0, 0),
+        (0, 128, 128),
+        (0, 0, 0),
+        (128, 128, 128),
+    ];
+
+    let (r, g, b) = COLORS[(number as usize + 7) % 8];
+
+    Color::RGB(r, g, b)
+}
+
+pub fn get_number_center(dst: Rect, shape: CellShape) -> Point {
+    match shape {
+        CellShape::Square | CellShape::Hex => Point::new(dst.x + 8, dst.y + 8),
+        CellShape::TriangleUp => Point::new(dst.x + 12, dst.y + 13),
+        CellShape::TriangleDown => Point::new(dst.x + 12, dst.y + 6),
+    }
+}
+
+// green for proved cells, yellow to red for guesses getting worse
+pub fn get_verdict_color(verdict: Verdict) -> Color {
+    match verdict {
+        Verdict::Forced => Color::RGB(0, 192, 0),
+        Verdict::Guess(_) => Color::RGB(255, 255, 0),
+        Verdict::NeedlessGuess(_) => Color::RGB(255, 128, 0),
+        Verdict::Blunder | Verdict::WrongFlag => Color::RGB(255, 0, 0),
+    }
+}
+
+// from green for safe cells through yellow to red for certain mines,
+// used as a colour modulation so the sprite stays visible
+pub fn get_chance_color(chance: f64) -> Color {
+    let chance = chance.clamp(0.0, 1.0);
+    let red = 96.0 + 159.0 * (chance * 2.0).min(1.0);
+    let green = 96.0 + 159.0 * ((1.0 - chance) * 2.0).min(1.0);
+
+    Color::RGB(red as u8, green as u8, 96)
 }
 
 pub fn get_digit_src(digit: i32) -> Rect {
     Rect::new(12 * digit, 33, 11, 21)
 }
 
+pub fn get_counter_src() -> Rect {
+    Rect::new(28, 82, 41, 25)
+}
+
 pub fn split_flags_by_digits(flags: i32) -> [i32; 3] {
     let flags = flags.clamp(-99, 999);
     let mut digits = [0; 3];
@@ -67,18 +188,149 @@ pub fn split_secs_by_digits(secs: u64) -> [i32; 3] {
     digits
 }
 
-pub fn get_cell_dst(x: usize, y: usize) -> Rect {
-    let x = x as i32 * 16 + 12;
-    let y = y as i32 * 16 + 55;
+// returns the digits to show and the separator placed before the given digit
+pub fn split_millis_by_digits(
+    millis: u64,
+    display: TimerDisplay,
+) -> (Vec<i32>, Option<(usize, Separator)>) {
+    let secs = millis / 1000 + 1;
+
+    match display {
+        TimerDisplay::Centiseconds if millis < 100_000 => {
+            (split_by_4_digits(millis / 10), Some((2, Separator::Point)))
+        }
+        TimerDisplay::Centiseconds if millis < 1_000_000 => {
+            (split_by_4_digits(millis / 100), Some((3, Separator::Point)))
+        }
+        TimerDisplay::Centiseconds => (split_by_4_digits(millis / 1000), None),
+        TimerDisplay::MinutesSeconds if secs > 999 => {
+            let secs = secs.min(99 * 60 + 59);
+            let digits = split_by_4_digits(secs / 60 * 100 + secs % 60);
+
+            (digits, Some((2, Separator::Colon)))
+        }
+        _ => (split_secs_by_digits(secs).to_vec(), None),
+    }
+}
+
+fn split_by_4_digits(number: u64) -> Vec<i32> {
+    let number = number.min(9999) as i32;
 
-    Rect::new(x, y, 16, 16)
+    vec![
+        number / 1000,
+        (number % 1000) / 100,
+        (number % 100) / 10,
+        number % 10,
+    ]
 }
 
-pub fn get_cell_pos(x: i32, y: i32) -> (usize, usize) {
-    let x = (x as usize - 12) / 16;
-    let y = (y as usize - 55) / 16;
+pub fn get_field_size(options: FieldOptions) -> (u32, u32) {
+    let cols = options.cols as u32;
+    let rows = options.rows as u32;
+
+    match options.tiling {
+        Tiling::Square => (cols * 16, rows * 16),
+        Tiling::Hex => (cols * 16 + 8, rows * 14 + 3),
+        Tiling::Triangle => (cols * 12 + 12, rows * 20),
+    }
+}
+
+// layers shown side by side are split by a narrow gap
+pub fn get_board_size(options: FieldOptions, layer_view: LayerView) -> (u32, u32) {
+    let (width, height) = get_field_size(options);
+
+    let layers = match layer_view {
+        LayerView::SideBySide => options.layers as u32,
+        LayerView::Single => 1,
+    };
+
+    (layers * (width + LAYER_GAP) - LAYER_GAP, height)
+}
+
+pub fn get_cell_shape(x: usize, y: usize, tiling: Tiling) -> CellShape {
+    match tiling {
+        Tiling::Square => CellShape::Square,
+        Tiling::Hex => CellShape::Hex,
+        Tiling::Triangle if (x + y).is_multiple_of(2) => CellShape::TriangleUp,
+        Tiling::Triangle => CellShape::TriangleDown,
+    }
+}
+
+pub fn get_cell_dst(x: usize, y: usize, tiling: Tiling) -> Rect {
+    let (x, y) = (x as i32, y as i32);
+
+    match tiling {
+        Tiling::Square => Rect::new(x * 16 + 12, y * 16 + 55, 16, 16),
+        Tiling::Hex => Rect::new(x * 16 + y % 2 * 8 + 12, y * 14 + 55, 16, 17),
+        Tiling::Triangle => Rect::new(x * 12 + 12, y * 20 + 55, 24, 20),
+    }
+}
+
+pub fn get_cell_pos(x: i32, y: i32, options: FieldOptions) -> Option<(usize, usize)> {
+    let x = x - 12;
+    let y = y - 55;
+
+    if x < 0 || y < 0 {
+        return None;
+    }
+
+    let (x, y) = match options.tiling {
+        Tiling::Square => (x / 16, y / 16),
+        Tiling::Hex => get_hex_pos(x, y)?,
+        Tiling::Triangle => get_triangle_pos(x, y)?,
+    };
+
+    let (x, y) = (x as usize, y as usize);
+
+    if x < options.cols && y < options.rows {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+// hexagons overlap their upper neighbours by three pixel rows
+fn get_hex_pos(x: i32, y: i32) -> Option<(i32, i32)> {
+    let row = y / 14;
+
+    [row, row - 1].into_iter().find_map(|row| {
+        let x = x - row % 2 * 8;
+        let y = y - row * 14;
+
+        if row < 0 || x < 0 || !(0..17).contains(&y) {
+            return None;
+        }
+
+        let width = (4 * (y + 1)).min(16).min(4 * (17 - y));
+        let left = (16 - width) / 2;
+
+        (left..left + width)
+            .contains(&(x % 16))
+            .then_some((x / 16, row))
+    })
+}
+
+// triangles overlap their left and right neighbours by half a box
+fn get_triangle_pos(x: i32, y: i32) -> Option<(i32, i32)> {
+    let row = y / 20;
+    let y = y % 20;
+    let half_width = y * 3 / 5 + 1;
+
+    [x / 12, x / 12 - 1].into_iter().find_map(|col| {
+        let x = x - col * 12;
+
+        if col < 0 || x >= 24 {
+            return None;
+        }
+
+        let inside = if (col + row) % 2 == 0 {
+            (12 - half_width..12 + half_width).contains(&x)
+        } else {
+            (half_width..24 - half_width).contains(&x)
+        };
 
-    (x, y)
+        inside.then_some((col, row))
+    })
 }
 
 pub fn refill_buffer(canvas: &mut WindowCanvas, texture_map: &Texture) -> SdlResult {
@@ -175,7 +427,7 @@ pub fn refill_buffer(canvas: &mut WindowCanvas, texture_map: &Texture) -> SdlRes
     }
 
     // drawing two counters
-    let src = Rect::new(28, 82, 41, 25);
+    let src = get_counter_src();
     let dst = Rect::new(16, 15, 41, 25);
 
     canvas.copy(texture_map, src, dst)?;
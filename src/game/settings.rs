@@ -0,0 +1,35 @@
+#[derive(Default, Clone, Copy)]
+pub struct Settings {
+    pub timer_display: TimerDisplay,
+    pub layer_view: LayerView,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum TimerDisplay {
+    // whole seconds, stops at 999
+    #[default]
+    Seconds,
+    // SS.CC, then SSS.C and SSSS as the time grows
+    Centiseconds,
+    // whole seconds, then MM:SS once 999 is passed
+    MinutesSeconds,
+}
+
+impl TimerDisplay {
+    // how often (in milliseconds) the counter has to be redrawn
+    pub fn step(self) -> u64 {
+        match self {
+            Self::Centiseconds => 10,
+            _ => 1000,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum LayerView {
+    // every layer at once, from left to right
+    #[default]
+    SideBySide,
+    // one layer at a time, switched with the mouse wheel or arrow keys
+    Single,
+}
@@ -0,0 +1,255 @@
+use super::{
+    clock::Clock,
+    components::{
+        counters::SecsCounter,
+        minefield::{CellState, Drill, MineCell, Pattern},
+    },
+    rendering::Renderer,
+    settings::Settings,
+    GameState,
+};
+use crate::{Context, DynResult, SdlResult};
+use std::{rc::Rc, time::Duration};
+
+// answers given on the positions of one pattern
+#[derive(Clone, Copy)]
+pub struct DrillStats {
+    pub pattern: Pattern,
+    // positions fully answered
+    pub drills: u32,
+    pub answers: u32,
+    pub correct: u32,
+    // time from showing a position to its first answer, summed over the positions
+    pub reaction_millis: u64,
+    pub reactions: u32,
+}
+
+impl DrillStats {
+    fn new(pattern: Pattern) -> Self {
+        Self {
+            pattern,
+            drills: 0,
+            answers: 0,
+            correct: 0,
+            reaction_millis: 0,
+            reactions: 0,
+        }
+    }
+
+    pub fn accuracy(&self) -> f64 {
+        self.correct as f64 / self.answers.max(1) as f64
+    }
+
+    pub fn mean_reaction_millis(&self) -> u64 {
+        self.reaction_millis / self.reactions.max(1) as u64
+    }
+
+    fn describe(&self) -> String {
+        let reaction = self.mean_reaction_millis();
+
+        format!(
+            "{}: {} drills, {:.0}% correct, {}.{:03} s to react",
+            self.pattern.name(),
+            self.drills,
+            self.accuracy() * 100.0,
+            reaction / 1000,
+            reaction % 1000
+        )
+    }
+}
+
+// left click marks a cell as safe and right click as mined, only cells proved
+// by the numbers count as right; the button or any click after the last answer
+// brings the next position, patterns are taken in turns
+pub struct TrainerHandler {
+    patterns: Vec<Pattern>,
+    turn: usize,
+    drill: Option<Drill>,
+    renderer: Renderer,
+    secs_counter: SecsCounter,
+    answered: bool,
+    stats: Vec<DrillStats>,
+    is_active: bool,
+}
+
+// public methods
+impl TrainerHandler {
+    pub fn init(
+        context: &Context,
+        patterns: &[Pattern],
+        settings: Settings,
+        clock: Rc<dyn Clock>,
+    ) -> DynResult<Self> {
+        let patterns = match patterns {
+            [] => Pattern::ALL.to_vec(),
+            patterns => patterns.to_vec(),
+        };
+
+        let drill = Drill::generate(patterns[0]).ok_or("no position found for the pattern")?;
+        let renderer = Renderer::init(context, drill.field.options(), settings)?;
+
+        let mut handler = Self {
+            stats: patterns
+                .iter()
+                .map(|&pattern| DrillStats::new(pattern))
+                .collect(),
+            patterns,
+            turn: 0,
+            drill: None,
+            renderer,
+            secs_counter: SecsCounter::new(clock),
+            answered: false,
+            is_active: true,
+        };
+
+        handler.show(drill);
+
+        Ok(handler)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn stats(&self) -> &[DrillStats] {
+        &self.stats
+    }
+
+    pub fn left_click(&mut self, x: i32, y: i32) {
+        if self.renderer.button_contains(x, y) || self.is_finished() {
+            self.next_drill();
+            return;
+        }
+
+        self.answer(x, y, false);
+    }
+
+    pub fn right_click(&mut self, x: i32, y: i32) {
+        if !self.is_finished() {
+            self.answer(x, y, true);
+        }
+    }
+
+    pub fn quit(&mut self) {
+        self.is_active = false;
+    }
+
+    pub fn next_update_in(&self) -> Option<Duration> {
+        let step = self.renderer.timer_step();
+        let millis = self.secs_counter.millis_to_next(step)?;
+
+        Some(Duration::from_millis(millis))
+    }
+
+    pub fn update_display(&mut self) -> SdlResult {
+        let step = self.renderer.timer_step();
+
+        if let Some(millis) = self.secs_counter.get_millis(step) {
+            self.renderer.draw_secs_counter(millis);
+        }
+
+        self.renderer.render_frame()
+    }
+}
+
+// private methods
+impl TrainerHandler {
+    fn next_drill(&mut self) {
+        self.secs_counter.stop();
+
+        // a pattern without positions is passed over
+        for _ in 0..self.patterns.len() {
+            self.turn = (self.turn + 1) % self.patterns.len();
+
+            if let Some(drill) = Drill::generate(self.patterns[self.turn]) {
+                self.show(drill);
+                return;
+            }
+        }
+    }
+
+    fn show(&mut self, drill: Drill) {
+        self.renderer.draw_initial_state(drill.targets.len() as i32);
+
+        for (x, y) in drill.field.get_coords_all() {
+            let cell = unsafe { drill.field.get_cell_unchecked(x, y) };
+
+            if cell.state() == CellState::Revealed {
+                self.renderer.draw_cell_default(&cell);
+            }
+        }
+
+        self.renderer
+            .set_title_note(Some(self.stats[self.turn].describe()));
+
+        self.secs_counter.reset();
+        self.secs_counter.start();
+        self.answered = false;
+        self.drill = Some(drill);
+    }
+
+    fn answer(&mut self, x: i32, y: i32, mined: bool) {
+        let Some(mut cell) = self.get_cell(x, y).filter(MineCell::is_hidden) else {
+            return;
+        };
+
+        let Some(drill) = &mut self.drill else {
+            return;
+        };
+
+        let stats = &mut self.stats[self.turn];
+        let coords = (cell.x(), cell.y());
+
+        if !self.answered {
+            stats.reaction_millis += self.secs_counter.elapsed_millis();
+            stats.reactions += 1;
+            self.answered = true;
+        }
+
+        stats.answers += 1;
+
+        if drill.proved.get(&coords) != Some(&mined) {
+            self.renderer.draw_mistake(coords.0, coords.1);
+            return;
+        }
+
+        stats.correct += 1;
+
+        cell.set_state(if mined {
+            CellState::Flagged(1)
+        } else {
+            CellState::Revealed
+        });
+
+        drill.field.set_cell(&cell);
+        drill.targets.retain(|&target| target != coords);
+
+        self.renderer.draw_cell_default(&cell);
+        self.renderer.draw_flags_counter(drill.targets.len() as i32);
+
+        if drill.targets.is_empty() {
+            self.secs_counter.stop();
+            self.renderer
+                .draw_secs_counter(self.secs_counter.elapsed_millis());
+
+            stats.drills += 1;
+
+            self.renderer.set_title_note(Some(stats.describe()));
+            self.renderer
+                .draw_button_by_gamestate(&GameState::Finished(true));
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.drill
+            .as_ref()
+            .is_none_or(|drill| drill.targets.is_empty())
+    }
+
+    fn get_cell(&self, x: i32, y: i32) -> Option<MineCell> {
+        let drill = self.drill.as_ref()?;
+        let (x, y) = self.renderer.get_cell_pos(x, y)?;
+
+        Some(unsafe { drill.field.get_cell_unchecked(x, y) })
+    }
+}
@@ -1,9 +1,22 @@
 mod game;
 
-use game::{FieldOptions, GameHandler};
+use game::{
+    components::puzzles::PuzzleSession, editor::EditorHandler, trainer::TrainerHandler, GameHandler,
+};
 
-use sdl2::{event::Event, mouse::MouseButton, EventPump, Sdl as Context};
-use std::time::Instant;
+pub use game::{
+    clock::{Clock, ManualClock, SystemClock},
+    settings::{LayerView, Settings, TimerDisplay},
+    trainer::DrillStats,
+    BigCount, BoardFile, BoardShape, ClusteredPlacement, Difficulty, FieldOptions, FirstClick,
+    GameAnalysis, GameResult, Hint, LayoutPlacement, Luck, MineField, MinePlacement, Move,
+    MoveReport, Neighbourhood, NoGuessPlacement, OffsetMask, Pattern, Placement, Rating,
+    SeededPlacement, SharedBoard, SymmetricPlacement, Symmetry, Tiling, TimedMove, Topology,
+    UniformPlacement, Verdict,
+};
+
+use sdl2::{event::Event, keyboard::Keycode, mouse::MouseButton, EventPump, Sdl as Context};
+use std::{path::Path, rc::Rc};
 
 // shortened error types
 pub type DynResult<T> = Result<T, Box<dyn std::error::Error>>;
@@ -11,11 +24,123 @@ pub type SdlResult = Result<(), String>;
 
 // start point
 pub fn run(cols: usize, rows: usize, mines: usize) -> DynResult<()> {
+    let options = FieldOptions {
+        cols,
+        rows,
+        layers: 1,
+        mines,
+        mines_per_cell: 1,
+        ..Default::default()
+    };
+
+    run_with_settings(options, Settings::default())?;
+
+    Ok(())
+}
+
+pub fn run_preset(path: impl AsRef<Path>) -> DynResult<()> {
+    let options = FieldOptions::load_preset(path)?;
+
+    run_with_settings(options, Settings::default())?;
+
+    Ok(())
+}
+
+// returns the results of all games finished during the session
+pub fn run_with_settings(options: FieldOptions, settings: Settings) -> DynResult<Vec<GameResult>> {
+    run_with_clock(options, settings, Rc::new(SystemClock::default()))
+}
+
+pub fn run_with_clock(
+    options: FieldOptions,
+    settings: Settings,
+    clock: Rc<dyn Clock>,
+) -> DynResult<Vec<GameResult>> {
+    run_game(options, settings, clock, options.placement.build(), None)
+}
+
+// overrides the placement picked in the options, e.g. with a custom strategy
+pub fn run_with_placement(
+    options: FieldOptions,
+    settings: Settings,
+    placement: Box<dyn MinePlacement>,
+) -> DynResult<Vec<GameResult>> {
+    run_game(
+        options,
+        settings,
+        Rc::new(SystemClock::default()),
+        placement,
+        None,
+    )
+}
+
+// plays the board of a share code
+pub fn run_shared(code: &str) -> DynResult<Vec<GameResult>> {
+    let board = SharedBoard::decode(code)?;
+    let (x, y) = board.first_click;
+
+    let context = sdl2::init()?;
+    let clock = Rc::new(SystemClock::default());
+    let placement = Box::new(board.placement());
+
+    let mut game_handler = GameHandler::init(
+        &context,
+        board.options,
+        Settings::default(),
+        clock,
+        placement,
+        None,
+    )?;
+    let mut event_pump = context.event_pump()?;
+
+    game_handler.show_note(format!("first opened at ({x},{y})"));
+
+    while game_handler.is_active() {
+        handle_game_events(&mut game_handler, &mut event_pump)?;
+    }
+
+    Ok(game_handler.results().to_vec())
+}
+
+// plays a board read from an MBF file or an ASCII grid,
+// the other options come from `base`
+pub fn run_board(base: FieldOptions, path: impl AsRef<Path>) -> DynResult<Vec<GameResult>> {
+    let board = BoardFile::load(path)?;
+    let options = FieldOptions {
+        luck: Luck::Fixed,
+        difficulty: Difficulty::Any,
+        ..board.options(base)?
+    };
+
+    run_with_placement(options, Settings::default(), Box::new(board.placement()))
+}
+
+// plays through a puzzle pack, starting at the first puzzle not solved yet
+pub fn run_puzzles(path: impl AsRef<Path>) -> DynResult<Vec<GameResult>> {
+    let puzzles = PuzzleSession::load(path)?;
+    let options = puzzles.field().options();
+
+    run_game(
+        options,
+        Settings::default(),
+        Rc::new(SystemClock::default()),
+        options.placement.build(),
+        Some(puzzles),
+    )
+}
+
+fn run_game(
+    options: FieldOptions,
+    settings: Settings,
+    clock: Rc<dyn Clock>,
+    placement: Box<dyn MinePlacement>,
+    puzzles: Option<PuzzleSession>,
+) -> DynResult<Vec<GameResult>> {
     //initialization
     let context = sdl2::init()?;
-    let options = FieldOptions { cols, rows, mines };
 
-    let mut game_handler = GameHandler::init(&context, options)?;
+    let mut game_handler =
+        GameHandler::init(&context, options, settings, clock, placement, puzzles)?;
     let mut event_pump = context.event_pump()?;
 
     //main game loop
@@ -23,13 +148,172 @@ pub fn run(cols: usize, rows: usize, mines: usize) -> DynResult<()> {
         handle_game_events(&mut game_handler, &mut event_pump)?;
     }
 
+    Ok(game_handler.results().to_vec())
+}
+
+// drills the given patterns in turns, all of them when none is given;
+// returns the stats of each pattern
+pub fn run_trainer(patterns: &[Pattern]) -> DynResult<Vec<DrillStats>> {
+    let context = sdl2::init()?;
+    let clock = Rc::new(SystemClock::default());
+
+    let mut trainer = TrainerHandler::init(&context, patterns, Settings::default(), clock)?;
+    let mut event_pump = context.event_pump()?;
+
+    while trainer.is_active() {
+        handle_trainer_events(&mut trainer, &mut event_pump)?;
+    }
+
+    Ok(trainer.stats().to_vec())
+}
+
+fn handle_trainer_events(trainer: &mut TrainerHandler, event_pump: &mut EventPump) -> SdlResult {
+    let event = match trainer.next_update_in() {
+        Some(timeout) => event_pump.wait_event_timeout(timeout.as_millis() as u32),
+        None => Some(event_pump.wait_event()),
+    };
+
+    for event in event.into_iter().chain(event_pump.poll_iter()) {
+        match event {
+            Event::MouseButtonDown {
+                mouse_btn: MouseButton::Left,
+                x,
+                y,
+                ..
+            } => trainer.left_click(x, y),
+            Event::MouseButtonDown {
+                mouse_btn: MouseButton::Right,
+                x,
+                y,
+                ..
+            } => trainer.right_click(x, y),
+            Event::Quit { .. } => {
+                trainer.quit();
+                break;
+            }
+            _ => (),
+        }
+    }
+
+    trainer.update_display()
+}
+
+// edits the board file, which is made when missing; the button or Enter plays
+// the board as it is and closing the game goes back to editing;
+// returns the results of all games played
+pub fn run_editor(options: FieldOptions, path: impl AsRef<Path>) -> DynResult<Vec<GameResult>> {
+    let path = path.as_ref();
+    let context = sdl2::init()?;
+    let settings = Settings::default();
+
+    let mut field = EditorHandler::load_board(options, path)?;
+    let mut results = Vec::new();
+    let mut event_pump = context.event_pump()?;
+
+    loop {
+        let mut editor = EditorHandler::init(&context, field, options.mines, settings, path)?;
+
+        while editor.is_active() {
+            handle_editor_events(&mut editor, &context, &mut event_pump)?;
+        }
+
+        if !editor.is_play_requested() {
+            break;
+        }
+
+        field = editor.into_field();
+
+        // the mines stay where they were put, unless under the first click
+        let options = FieldOptions {
+            luck: Luck::Fixed,
+            difficulty: Difficulty::Any,
+            ..field.options()
+        };
+        let placement = Box::new(LayoutPlacement::from_text(&field.to_layout())?);
+        let clock = Rc::new(SystemClock::default());
+
+        let mut game_handler =
+            GameHandler::init(&context, options, settings, clock, placement, None)?;
+
+        while game_handler.is_active() {
+            handle_game_events(&mut game_handler, &mut event_pump)?;
+        }
+
+        results.extend_from_slice(game_handler.results());
+    }
+
+    Ok(results)
+}
+
+fn handle_editor_events(
+    editor: &mut EditorHandler,
+    context: &Context,
+    event_pump: &mut EventPump,
+) -> DynResult<()> {
+    let event = event_pump.wait_event();
+
+    for event in std::iter::once(event).chain(event_pump.poll_iter()) {
+        match event {
+            Event::MouseButtonDown {
+                mouse_btn: MouseButton::Left,
+                x,
+                y,
+                ..
+            } => editor.left_click(x, y),
+            Event::MouseButtonDown {
+                mouse_btn: MouseButton::Right,
+                x,
+                y,
+                ..
+            } => editor.right_click(x, y),
+            Event::KeyDown {
+                keycode: Some(keycode),
+                ..
+            } => handle_editor_key(editor, context, keycode)?,
+            Event::Quit { .. } => editor.quit(),
+            _ => (),
+        }
+
+        if !editor.is_active() {
+            break;
+        }
+    }
+
+    Ok(editor.update_display()?)
+}
+
+// C clears the board, R fills it with random mines, M mirrors it, the arrows
+// resize it, S saves it, E exports it as a puzzle and Enter plays it
+fn handle_editor_key(
+    editor: &mut EditorHandler,
+    context: &Context,
+    keycode: Keycode,
+) -> DynResult<()> {
+    match keycode {
+        Keycode::C => editor.clear(),
+        Keycode::R => editor.fill(),
+        Keycode::M => editor.mirror(),
+        Keycode::Left => editor.resize(context, -1, 0)?,
+        Keycode::Right => editor.resize(context, 1, 0)?,
+        Keycode::Up => editor.resize(context, 0, -1)?,
+        Keycode::Down => editor.resize(context, 0, 1)?,
+        Keycode::S => editor.save(),
+        Keycode::E => editor.export_puzzle(),
+        Keycode::Return => editor.play(),
+        _ => (),
+    }
+
     Ok(())
 }
 
+// sleeps until input arrives or the seconds counter has to change
 fn handle_game_events(game_handler: &mut GameHandler, event_pump: &mut EventPump) -> SdlResult {
-    let now = Instant::now();
+    let event = match game_handler.next_update_in() {
+        Some(timeout) => event_pump.wait_event_timeout(timeout.as_millis() as u32),
+        None => Some(event_pump.wait_event()),
+    };
 
-    for event in event_pump.poll_iter() {
+    for event in event.into_iter().chain(event_pump.poll_iter()) {
         match event {
             Event::MouseButtonDown {
                 mouse_btn: MouseButton::Left,
@@ -42,6 +326,8 @@ fn handle_game_events(game_handler: &mut GameHandler, event_pump: &mut EventPump
             } => {
                 if mousestate.left() {
                     game_handler.mouse_move(x, y);
+                } else {
+                    game_handler.hover(x, y);
                 }
             }
             Event::MouseButtonUp {
@@ -56,6 +342,35 @@ fn handle_game_events(game_handler: &mut GameHandler, event_pump: &mut EventPump
                 y,
                 ..
             } => game_handler.right_click(x, y),
+            Event::MouseWheel { y, .. } => game_handler.scroll_layer(-y.signum()),
+            Event::KeyDown {
+                keycode: Some(Keycode::Up),
+                ..
+            } => game_handler.scroll_layer(-1),
+            Event::KeyDown {
+                keycode: Some(Keycode::Down),
+                ..
+            } => game_handler.scroll_layer(1),
+            Event::KeyDown {
+                keycode: Some(Keycode::H),
+                ..
+            } => game_handler.hint(),
+            Event::KeyDown {
+                keycode: Some(Keycode::A),
+                ..
+            } => game_handler.toggle_analysis(),
+            Event::KeyDown {
+                keycode: Some(Keycode::P),
+                ..
+            } => game_handler.toggle_heatmap(),
+            Event::KeyDown {
+                keycode: Some(Keycode::N),
+                ..
+            } => game_handler.skip_puzzle(),
+            Event::KeyDown {
+                keycode: Some(Keycode::C),
+                ..
+            } => game_handler.share(),
             Event::Quit { .. } => {
                 game_handler.quit();
                 break;
@@ -64,5 +379,5 @@ fn handle_game_events(game_handler: &mut GameHandler, event_pump: &mut EventPump
         }
     }
 
-    game_handler.next_frame(now)
+    game_handler.update_display()
 }
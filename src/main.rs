@@ -1,9 +1,99 @@
 #![windows_subsystem = "windows"]
 
+use minesweeper::{FieldOptions, LayoutPlacement, Pattern, Settings};
+
 fn main() -> minesweeper::DynResult<()> {
     let cols = 16;
     let rows = 16;
     let mines = 40;
 
-    minesweeper::run(cols, rows, mines)
+    // a board preset file can be given as the first argument
+    // and a file with a fixed mine layout as the second one;
+    // `drill [pattern...]` starts the pattern trainer instead
+    // and `puzzles <pack>` plays through a puzzle pack;
+    // `editor <board> [preset]` edits a board file by hand
+    // and `board <file> [preset]` plays an MBF or ASCII board;
+    // `share <code>` plays the board of a share code
+    let mut args = std::env::args().skip(1).peekable();
+
+    if args.peek().is_some_and(|arg| arg == "drill") {
+        let patterns = args
+            .skip(1)
+            .map(|name| Pattern::from_name(&name).ok_or(format!("unknown pattern {name}")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for stats in minesweeper::run_trainer(&patterns)? {
+            println!(
+                "{}: {} drills, {:.0}% correct, {} ms to react",
+                stats.pattern.name(),
+                stats.drills,
+                stats.accuracy() * 100.0,
+                stats.mean_reaction_millis()
+            );
+        }
+
+        return Ok(());
+    }
+
+    if args.peek().is_some_and(|arg| arg == "puzzles") {
+        let pack = args.nth(1).ok_or("usage: puzzles <pack>")?;
+        minesweeper::run_puzzles(pack)?;
+
+        return Ok(());
+    }
+
+    if args.peek().is_some_and(|arg| arg == "editor") {
+        let board = args.nth(1).ok_or("usage: editor <board> [preset]")?;
+
+        let options = match args.next() {
+            Some(preset) => FieldOptions::load_preset(preset)?,
+            None => FieldOptions {
+                cols,
+                rows,
+                layers: 1,
+                mines,
+                mines_per_cell: 1,
+                ..Default::default()
+            },
+        };
+
+        minesweeper::run_editor(options, board)?;
+
+        return Ok(());
+    }
+
+    if args.peek().is_some_and(|arg| arg == "board") {
+        let board = args.nth(1).ok_or("usage: board <file> [preset]")?;
+
+        let base = match args.next() {
+            Some(preset) => FieldOptions::load_preset(preset)?,
+            None => FieldOptions::default(),
+        };
+
+        minesweeper::run_board(base, board)?;
+
+        return Ok(());
+    }
+
+    if args.peek().is_some_and(|arg| arg == "share") {
+        let code = args.nth(1).ok_or("usage: share <code>")?;
+        minesweeper::run_shared(&code)?;
+
+        return Ok(());
+    }
+
+    match (args.next(), args.next()) {
+        (Some(preset), Some(layout)) => {
+            let options = FieldOptions {
+                placement: LayoutPlacement::load(layout)?.into_placement(),
+                ..FieldOptions::load_preset(preset)?
+            };
+
+            minesweeper::run_with_settings(options, Settings::default())?;
+
+            Ok(())
+        }
+        (Some(preset), None) => minesweeper::run_preset(preset),
+        _ => minesweeper::run(cols, rows, mines),
+    }
 }